@@ -0,0 +1,59 @@
+//! [quickcheck](https://docs.rs/quickcheck) support for tuples, enabled by `quickcheck` feature.
+//!
+//! Supported tuples are up to 12 elements, because `ArbitraryTuple` derives `Debug`.
+use quickcheck::{Arbitrary, Gen};
+
+/// A wrapper of `(a, a, a, ...)` implementing `Arbitrary`, which is generated by
+/// `a`'s `Arbitrary` implementation and shrunk element-wise.
+/// # Example
+/// ```
+/// # extern crate quickcheck;
+/// # extern crate tuple_map;
+/// use quickcheck::quickcheck;
+/// use tuple_map::arbitrary::ArbitraryTuple;
+/// use tuple_map::*;
+/// fn prop(a: ArbitraryTuple<(u8, u8, u8)>) -> bool {
+///     a.0.map(u32::from).sum() <= 255 * 3
+/// }
+/// # fn main() {
+/// quickcheck(prop as fn(ArbitraryTuple<(u8, u8, u8)>) -> bool);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ArbitraryTuple<U>(pub U);
+
+macro_rules! impl_arbitrary {
+    ($($t: ident)+; $($idx: tt)+) => {
+        impl<T: Arbitrary> Arbitrary for ArbitraryTuple<($($t,)+)> {
+            fn arbitrary(g: &mut Gen) -> Self {
+                ArbitraryTuple(($($t::arbitrary(g),)+))
+            }
+
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                let mut shrunk: Vec<Box<dyn Iterator<Item = Self>>> = Vec::new();
+                $(
+                    let this = self.clone();
+                    shrunk.push(Box::new(self.0.$idx.shrink().map(move |x| {
+                        let mut a = this.clone();
+                        a.0.$idx = x;
+                        a
+                    })));
+                )+
+                Box::new(shrunk.into_iter().flatten())
+            }
+        }
+    };
+}
+
+impl_arbitrary!(T; 0);
+impl_arbitrary!(T T; 0 1);
+impl_arbitrary!(T T T; 0 1 2);
+impl_arbitrary!(T T T T; 0 1 2 3);
+impl_arbitrary!(T T T T T; 0 1 2 3 4);
+impl_arbitrary!(T T T T T T; 0 1 2 3 4 5);
+impl_arbitrary!(T T T T T T T; 0 1 2 3 4 5 6);
+impl_arbitrary!(T T T T T T T T; 0 1 2 3 4 5 6 7);
+impl_arbitrary!(T T T T T T T T T; 0 1 2 3 4 5 6 7 8);
+impl_arbitrary!(T T T T T T T T T T; 0 1 2 3 4 5 6 7 8 9);
+impl_arbitrary!(T T T T T T T T T T T; 0 1 2 3 4 5 6 7 8 9 10);
+impl_arbitrary!(T T T T T T T T T T T T; 0 1 2 3 4 5 6 7 8 9 10 11);
@@ -0,0 +1,396 @@
+//! Conversions and comparisons between tuples of different arities, used by methods like
+//! [`TupleMap2::zip_pad`](../trait.TupleMap2.html#method.zip_pad) or
+//! [`TupleMap2::starts_with`](../trait.TupleMap2.html#method.starts_with) or
+//! [`TupleMap2::concat`](../trait.TupleMap2.html#method.concat) or
+//! [`TupleMap2::flatten`](../trait.TupleMap2.html#method.flatten) or
+//! [`TupleMap2::split_at`](../trait.TupleMap2.html#method.split_at) or
+//! [`TupleMap2::widen`](../trait.TupleMap2.html#method.widen).
+//! [`MapAt`](trait.MapAt.html) maps one element of a tuple whose elements may have different
+//! types.
+
+/// Tuples which can be padded into a longer (or the same length) tuple `Target`.
+/// # Example
+/// ```
+/// # use tuple_map::arity::Pad;
+/// let a: (i32, i32, i32, i32) = (1, 2).pad(0);
+/// assert_eq!(a, (1, 2, 0, 0));
+/// ```
+pub trait Pad<Target> {
+    type Item;
+    /// Appends clones of `fill` until the length reaches that of `Target`.
+    fn pad(self, fill: Self::Item) -> Target;
+}
+
+/// Sequences which can be a prefix or suffix of `Target`, i.e. shorter(or the same length)
+/// tuples, slices and arrays.
+/// # Example
+/// ```
+/// # use tuple_map::arity::Affix;
+/// assert!((1, 2).is_prefix_of(&(1, 2, 3)));
+/// assert!([2, 3].is_suffix_of(&(1, 2, 3)));
+/// ```
+pub trait Affix<Target> {
+    /// Checks if `self` matches the leading elements of `target`.
+    fn is_prefix_of(&self, target: &Target) -> bool;
+    /// Checks if `self` matches the trailing elements of `target`.
+    fn is_suffix_of(&self, target: &Target) -> bool;
+}
+
+/// Tuples which can be followed by `Rhs`, i.e. pairs of tuples whose total length is
+/// at most 16.
+/// # Example
+/// ```
+/// # use tuple_map::arity::Append;
+/// assert_eq!((1, 2).append((3,)), (1, 2, 3));
+/// ```
+pub trait Append<Rhs> {
+    /// The concatenated tuple.
+    type Output;
+    /// Returns `self` followed by `rhs`.
+    fn append(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Tuples of tuples which can be flattened, i.e. `((T, ..., T), ..., (T, ..., T))` where
+/// all inner tuples have the same length and the total length is at most 16.
+/// # Example
+/// ```
+/// # use tuple_map::arity::Flatten;
+/// assert_eq!(((1, 2), (3, 4), (5, 6)).into_flat(), (1, 2, 3, 4, 5, 6));
+/// ```
+pub trait Flatten {
+    /// The flattened tuple.
+    type Output;
+    /// Concatenates all inner tuples.
+    fn into_flat(self) -> Self::Output;
+}
+
+/// Tuples which can be split into the first `K` elements and the rest, i.e. tuples whose
+/// length is at least `K`.
+/// # Example
+/// ```
+/// # use tuple_map::arity::SplitAt;
+/// assert_eq!(SplitAt::<1>::split_tuple((1, 2, 3)), ((1,), (2, 3)));
+/// ```
+pub trait SplitAt<const K: usize> {
+    /// The tuple of the first `K` elements.
+    type Front;
+    /// The tuple of the remaining elements.
+    type Back;
+    /// Splits `self` into the first `K` elements and the rest.
+    fn split_tuple(self) -> (Self::Front, Self::Back);
+}
+
+/// Tuples into which an element of type `X` can be inserted at index `I`.
+///
+/// This is implemented for all tuples which can be split by [`SplitAt<I>`](trait.SplitAt.html)
+/// and whose length is less than 16.
+/// # Example
+/// ```
+/// # use tuple_map::arity::InsertAt;
+/// assert_eq!(InsertAt::<1, _>::insert_tuple((1, 3), 2), (1, 2, 3));
+/// ```
+pub trait InsertAt<const I: usize, X> {
+    /// The tuple after insertion.
+    type Output;
+    /// Inserts `x` so that it becomes the `I`th element.
+    fn insert_tuple(self, x: X) -> Self::Output;
+}
+
+impl<U, X, const I: usize> InsertAt<I, X> for U
+where
+    U: SplitAt<I>,
+    U::Front: Append<(X,)>,
+    <U::Front as Append<(X,)>>::Output: Append<U::Back>,
+{
+    type Output = <<U::Front as Append<(X,)>>::Output as Append<U::Back>>::Output;
+    fn insert_tuple(self, x: X) -> Self::Output {
+        let (front, back) = self.split_tuple();
+        front.append((x,)).append(back)
+    }
+}
+
+/// Tuples which can be extended to a tuple of length `M` by appending `Default::default()`.
+/// # Example
+/// ```
+/// # use tuple_map::arity::Widen;
+/// assert_eq!(Widen::<4>::widen_tuple((1, 2)), (1, 2, 0, 0));
+/// ```
+pub trait Widen<const M: usize> {
+    /// The tuple of length `M`.
+    type Output;
+    /// Appends default values until the length reaches `M`.
+    fn widen_tuple(self) -> Self::Output;
+}
+
+/// Tuples whose `I`th element can be mapped into a value of another type.
+///
+/// The index is a parameter of the trait, so it is written as `MapAt::<I>::map_at(t, f)`.
+/// The elements don't need to have the same type, so calls can be chained.
+/// # Example
+/// ```
+/// # use tuple_map::arity::MapAt;
+/// assert_eq!(MapAt::<1>::map_at((1, 2, 3), |x| x > 1), (1, true, 3));
+/// let a = MapAt::<0>::map_at((1, "ab"), |x| x as f64 / 2.0);
+/// assert_eq!(MapAt::<1>::map_at(a, str::len), (0.5, 2));
+/// ```
+pub trait MapAt<const I: usize> {
+    /// The type of the `I`th element.
+    type Item;
+    /// The tuple whose `I`th element is replaced by `B`.
+    type Output<B>;
+    /// Applies `f` to the `I`th element.
+    fn map_at<B, F>(self, f: F) -> Self::Output<B>
+    where
+        F: FnOnce(Self::Item) -> B;
+}
+
+/// Tuples from which the `I`th element of type `X` can be removed.
+/// # Example
+/// ```
+/// # use tuple_map::arity::RemoveAt;
+/// assert_eq!(RemoveAt::<1, _>::remove_tuple((1, 2, 3)), (2, (1, 3)));
+/// ```
+pub trait RemoveAt<const I: usize, X> {
+    /// The tuple after removal.
+    type Output;
+    /// Removes the `I`th element and returns it with the remaining tuple.
+    fn remove_tuple(self) -> (X, Self::Output);
+}
+
+impl<U, X, const I: usize> RemoveAt<I, X> for U
+where
+    U: SplitAt<I>,
+    U::Back: SplitAt<1, Front = (X,)>,
+    U::Front: Append<<U::Back as SplitAt<1>>::Back>,
+{
+    type Output = <U::Front as Append<<U::Back as SplitAt<1>>::Back>>::Output;
+    fn remove_tuple(self) -> (X, Self::Output) {
+        let (front, back) = self.split_tuple();
+        let ((x,), rest) = SplitAt::<1>::split_tuple(back);
+        (x, front.append(rest))
+    }
+}
+
+impl Append<()> for () {
+    type Output = ();
+    fn append(self, _rhs: ()) {}
+}
+
+macro_rules! impl_arity {
+    ($($name: ident)+) => {
+        impl_arity!(@outer [] [$($name)+]);
+    };
+    (@outer [$($done: ident)*] [$next: ident $($rest: ident)*]) => {
+        impl_arity!(@slice $($done)* $next);
+        impl_arity!(@empty $($done)* $next);
+        impl_arity!(@inner [] [$($done)* $next]);
+        impl_arity!(@outer [$($done)* $next] [$($rest)*]);
+    };
+    (@outer [$($done: ident)*] []) => {};
+    (@inner [$($kept: ident)*] [$next: ident $($pad: ident)*]) => {
+        impl_arity!(@pair [$($kept)* $next] [$($pad)*]);
+        impl_arity!(@inner [$($kept)* $next] [$($pad)*]);
+    };
+    (@inner [$($kept: ident)*] []) => {};
+    (@empty $($name: ident)+) => {
+        impl<T> Append<($(replace_ty!($name T),)+)> for () {
+            type Output = ($(replace_ty!($name T),)+);
+            fn append(self, rhs: Self::Output) -> Self::Output {
+                rhs
+            }
+        }
+        impl<T> SplitAt<0> for ($(replace_ty!($name T),)+) {
+            type Front = ();
+            type Back = Self;
+            fn split_tuple(self) -> ((), Self) {
+                ((), self)
+            }
+        }
+    };
+    (@slice $($name: ident)+) => {
+        impl<T: PartialEq> Affix<($(replace_ty!($name T),)+)> for [T] {
+            fn is_prefix_of(&self, target: &($(replace_ty!($name T),)+)) -> bool {
+                let ($(ref $name,)+) = *target;
+                let target = [$($name,)+];
+                self.len() <= target.len() && self.iter().zip(target.iter()).all(|(a, b)| a == *b)
+            }
+            fn is_suffix_of(&self, target: &($(replace_ty!($name T),)+)) -> bool {
+                let ($(ref $name,)+) = *target;
+                let target = [$($name,)+];
+                self.len() <= target.len()
+                    && self.iter().rev().zip(target.iter().rev()).all(|(a, b)| a == *b)
+            }
+        }
+        impl<T: PartialEq, const N: usize> Affix<($(replace_ty!($name T),)+)> for [T; N] {
+            fn is_prefix_of(&self, target: &($(replace_ty!($name T),)+)) -> bool {
+                self[..].is_prefix_of(target)
+            }
+            fn is_suffix_of(&self, target: &($(replace_ty!($name T),)+)) -> bool {
+                self[..].is_suffix_of(target)
+            }
+        }
+    };
+    (@pair [$($kept: ident)+] [$($pad: ident)*]) => {
+        impl<T: PartialEq> Affix<($(replace_ty!($kept T),)+ $(replace_ty!($pad T),)*)>
+            for ($(replace_ty!($kept T),)+)
+        {
+            fn is_prefix_of(&self, target: &($(replace_ty!($kept T),)+ $(replace_ty!($pad T),)*)) -> bool {
+                let this = {
+                    let ($(ref $kept,)+) = *self;
+                    [$($kept,)+]
+                };
+                let ($(ref $kept,)+ $(ref $pad,)*) = *target;
+                let target = [$($kept,)+ $($pad,)*];
+                this[..] == target[..this.len()]
+            }
+            fn is_suffix_of(&self, target: &($(replace_ty!($kept T),)+ $(replace_ty!($pad T),)*)) -> bool {
+                let this = {
+                    let ($(ref $kept,)+) = *self;
+                    [$($kept,)+]
+                };
+                let ($(ref $kept,)+ $(ref $pad,)*) = *target;
+                let target = [$($kept,)+ $($pad,)*];
+                this[..] == target[target.len() - this.len()..]
+            }
+        }
+        impl<T> Append<($(replace_ty!($pad T),)*)> for ($(replace_ty!($kept T),)+) {
+            type Output = ($(replace_ty!($kept T),)+ $(replace_ty!($pad T),)*);
+            fn append(self, rhs: ($(replace_ty!($pad T),)*)) -> Self::Output {
+                let ($($kept,)+) = self;
+                let ($($pad,)*) = rhs;
+                ($($kept,)+ $($pad,)*)
+            }
+        }
+        impl<T> SplitAt<{ 0 $(+ replace_expr!($kept 1))+ }>
+            for ($(replace_ty!($kept T),)+ $(replace_ty!($pad T),)*)
+        {
+            type Front = ($(replace_ty!($kept T),)+);
+            type Back = ($(replace_ty!($pad T),)*);
+            fn split_tuple(self) -> (Self::Front, Self::Back) {
+                let ($($kept,)+ $($pad,)*) = self;
+                (($($kept,)+), ($($pad,)*))
+            }
+        }
+        impl<T: Default> Widen<{ 0 $(+ replace_expr!($kept 1))+ $(+ replace_expr!($pad 1))* }>
+            for ($(replace_ty!($kept T),)+)
+        {
+            type Output = ($(replace_ty!($kept T),)+ $(replace_ty!($pad T),)*);
+            fn widen_tuple(self) -> Self::Output {
+                let ($($kept,)+) = self;
+                ($($kept,)+ $(replace_expr!($pad T::default()),)*)
+            }
+        }
+        impl<T: Clone> Pad<($(replace_ty!($kept T),)+ $(replace_ty!($pad T),)*)>
+            for ($(replace_ty!($kept T),)+)
+        {
+            type Item = T;
+            #[allow(unused_variables)]
+            fn pad(self, fill: T) -> ($(replace_ty!($kept T),)+ $(replace_ty!($pad T),)*) {
+                let ($($kept,)+) = self;
+                ($($kept,)+ $(replace_expr!($pad fill.clone()),)*)
+            }
+        }
+    };
+}
+
+impl_arity!(a b c d e f g h i j k l m n o p);
+
+macro_rules! impl_map_at {
+    ($(($ty: ident $name: ident))+) => {
+        impl_map_at!(@outer [] [$(($ty $name))+]);
+    };
+    (@outer [$($done: tt)*] [$next: tt $($rest: tt)*]) => {
+        impl_map_at!(@inner [] [$($done)* $next]);
+        impl_map_at!(@outer [$($done)* $next] [$($rest)*]);
+    };
+    (@outer [$($done: tt)*] []) => {};
+    (@inner [$($kept: tt)*] [$at: tt $($rest: tt)*]) => {
+        impl_map_at!(@impl [$($kept)*] $at [$($rest)*]);
+        impl_map_at!(@inner [$($kept)* $at] [$($rest)*]);
+    };
+    (@inner [$($kept: tt)*] []) => {};
+    (@impl [$(($kept_ty: ident $kept: ident))*] ($ty: ident $name: ident)
+     [$(($rest_ty: ident $rest: ident))*]) => {
+        impl<$($kept_ty,)* $ty, $($rest_ty,)*> MapAt<{ 0 $(+ replace_expr!($kept 1))* }>
+            for ($($kept_ty,)* $ty, $($rest_ty,)*)
+        {
+            type Item = $ty;
+            type Output<Out> = ($($kept_ty,)* Out, $($rest_ty,)*);
+            fn map_at<Out, Fun>(self, f: Fun) -> Self::Output<Out>
+            where
+                Fun: FnOnce($ty) -> Out,
+            {
+                let ($($kept,)* $name, $($rest,)*) = self;
+                ($($kept,)* f($name), $($rest,)*)
+            }
+        }
+    };
+}
+
+impl_map_at!(
+    (A a) (B b) (C c) (D d) (E e) (F f) (G g) (H h)
+    (I i) (J j) (K k) (L l) (M m) (N n) (O o) (P p)
+);
+
+macro_rules! impl_flatten {
+    ($(($($name: ident)+))+) => {
+        impl<T> Flatten for ($(($(replace_ty!($name T),)+),)+) {
+            type Output = ($($(replace_ty!($name T),)+)+);
+            fn into_flat(self) -> Self::Output {
+                let ($(($($name,)+),)+) = self;
+                ($($($name,)+)+)
+            }
+        }
+    };
+}
+
+impl_flatten!((a));
+impl_flatten!((a b));
+impl_flatten!((a b c));
+impl_flatten!((a b c d));
+impl_flatten!((a b c d e));
+impl_flatten!((a b c d e f));
+impl_flatten!((a b c d e f g));
+impl_flatten!((a b c d e f g h));
+impl_flatten!((a b c d e f g h i));
+impl_flatten!((a b c d e f g h i j));
+impl_flatten!((a b c d e f g h i j k));
+impl_flatten!((a b c d e f g h i j k l));
+impl_flatten!((a b c d e f g h i j k l m));
+impl_flatten!((a b c d e f g h i j k l m n));
+impl_flatten!((a b c d e f g h i j k l m n o));
+impl_flatten!((a b c d e f g h i j k l m n o p));
+impl_flatten!((a) (b));
+impl_flatten!((a b) (c d));
+impl_flatten!((a b c) (d e f));
+impl_flatten!((a b c d) (e f g h));
+impl_flatten!((a b c d e) (f g h i j));
+impl_flatten!((a b c d e f) (g h i j k l));
+impl_flatten!((a b c d e f g) (h i j k l m n));
+impl_flatten!((a b c d e f g h) (i j k l m n o p));
+impl_flatten!((a) (b) (c));
+impl_flatten!((a b) (c d) (e f));
+impl_flatten!((a b c) (d e f) (g h i));
+impl_flatten!((a b c d) (e f g h) (i j k l));
+impl_flatten!((a b c d e) (f g h i j) (k l m n o));
+impl_flatten!((a) (b) (c) (d));
+impl_flatten!((a b) (c d) (e f) (g h));
+impl_flatten!((a b c) (d e f) (g h i) (j k l));
+impl_flatten!((a b c d) (e f g h) (i j k l) (m n o p));
+impl_flatten!((a) (b) (c) (d) (e));
+impl_flatten!((a b) (c d) (e f) (g h) (i j));
+impl_flatten!((a b c) (d e f) (g h i) (j k l) (m n o));
+impl_flatten!((a) (b) (c) (d) (e) (f));
+impl_flatten!((a b) (c d) (e f) (g h) (i j) (k l));
+impl_flatten!((a) (b) (c) (d) (e) (f) (g));
+impl_flatten!((a b) (c d) (e f) (g h) (i j) (k l) (m n));
+impl_flatten!((a) (b) (c) (d) (e) (f) (g) (h));
+impl_flatten!((a b) (c d) (e f) (g h) (i j) (k l) (m n) (o p));
+impl_flatten!((a) (b) (c) (d) (e) (f) (g) (h) (i));
+impl_flatten!((a) (b) (c) (d) (e) (f) (g) (h) (i) (j));
+impl_flatten!((a) (b) (c) (d) (e) (f) (g) (h) (i) (j) (k));
+impl_flatten!((a) (b) (c) (d) (e) (f) (g) (h) (i) (j) (k) (l));
+impl_flatten!((a) (b) (c) (d) (e) (f) (g) (h) (i) (j) (k) (l) (m));
+impl_flatten!((a) (b) (c) (d) (e) (f) (g) (h) (i) (j) (k) (l) (m) (n));
+impl_flatten!((a) (b) (c) (d) (e) (f) (g) (h) (i) (j) (k) (l) (m) (n) (o));
+impl_flatten!((a) (b) (c) (d) (e) (f) (g) (h) (i) (j) (k) (l) (m) (n) (o) (p));
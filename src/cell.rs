@@ -0,0 +1,18 @@
+//! Interior mutability helpers for tuples.
+
+/// Projects `&Cell<(T, T, ...)>` into `(&Cell<T>, &Cell<T>, ...)`, like
+/// [`Cell::as_slice_of_cells`](https://doc.rust-lang.org/std/cell/struct.Cell.html#method.as_slice_of_cells).
+/// # Example
+/// ```
+/// # use tuple_map::cell::AsCells;
+/// use std::cell::Cell;
+/// let a = Cell::new((3, 4, 5));
+/// let (x, y, z) = a.as_cells();
+/// x.set(y.get() + z.get());
+/// assert_eq!(a.get(), (9, 4, 5));
+/// ```
+pub trait AsCells<'a> {
+    type Output;
+    /// Returns a tuple of references to each element as `Cell`.
+    fn as_cells(&'a self) -> Self::Output;
+}
@@ -0,0 +1,82 @@
+//! Error types returned by fallible methods of tuples.
+use std::error::Error;
+use std::fmt;
+
+/// The error returned by
+/// [`TupleMap2::get_many_mut`](../trait.TupleMap2.html#method.get_many_mut).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GetManyMutError {
+    /// An index is not smaller than the length of the tuple.
+    IndexOutOfBounds {
+        /// The requested index.
+        index: usize,
+        /// The length of the tuple.
+        len: usize,
+    },
+    /// The same index is requested more than once.
+    OverlappingIndices {
+        /// The duplicated index.
+        index: usize,
+    },
+}
+
+impl fmt::Display for GetManyMutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GetManyMutError::IndexOutOfBounds { index, len } => write!(
+                f,
+                "index {} is out of bounds for a tuple of length {}",
+                index, len
+            ),
+            GetManyMutError::OverlappingIndices { index } => {
+                write!(f, "index {} is requested more than once", index)
+            }
+        }
+    }
+}
+
+impl Error for GetManyMutError {}
+
+/// The error returned when building a tuple from a sequence of a different length, e.g. by
+/// [`TupleMap2::try_from_vec`](../trait.TupleMap2.html#method.try_from_vec).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LenError {
+    /// The length of the tuple.
+    pub expected: usize,
+    /// The length of the given sequence.
+    pub actual: usize,
+}
+
+impl fmt::Display for LenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "expected a sequence of length {}, but got length {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl Error for LenError {}
+
+/// The error returned when an index is not smaller than the length of the tuple, e.g. by
+/// [`TupleMap2::set`](../trait.TupleMap2.html#method.set).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutOfRange {
+    /// The requested index.
+    pub index: usize,
+    /// The length of the tuple.
+    pub len: usize,
+}
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "index {} is out of bounds for a tuple of length {}",
+            self.index, self.len
+        )
+    }
+}
+
+impl Error for OutOfRange {}
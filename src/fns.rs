@@ -0,0 +1,238 @@
+//! Free functions generic over tuples of any arity.
+//!
+//! All functions here takes any tuple implementing [`Tuple`](trait.Tuple.html), which is
+//! implemented for all `(T,)`, `(T, T)`, ..., `(T, T, ..., T)`(16 elements) and arrays
+//! `[T; 1]`, ..., `[T; 16]`.
+//! Unlike `TupleMap1`, `TupleMap2`, ..., [`Tuple`](trait.Tuple.html) is a single trait for all
+//! lengths, which uses a generic associated type `Mapped<B>` for the results of mapping.
+//! # Example
+//! ```
+//! use tuple_map::fns;
+//! fn double<T: fns::Tuple<Item = i32>>(t: T) -> T::Mapped<i32> {
+//!     fns::map(t, |x| x * 2)
+//! }
+//! assert_eq!(double((1, 2)), (2, 4));
+//! assert_eq!(double((1, 2, 3)), (2, 4, 6));
+//! assert_eq!(double([1, 2, 3]), [2, 4, 6]);
+//! ```
+//!
+//! **Notes**
+//! [`Tuple`](trait.Tuple.html) is not exported from the crate root, because its methods
+//! conflict with methods of `TupleMap1`, `TupleMap2`, ... .
+//!
+//! [`Tuple`](trait.Tuple.html) has only the methods whose signatures don't depend on the length,
+//! and it doesn't replace `TupleMap1`, `TupleMap2`, ... . Many of their methods, like
+//! `pairwise`, `split_at` or `curry`, return tuples or closures whose length is computed from
+//! the length of `self`, which can't be written in a single trait without generic const
+//! expressions.
+
+/// The common trait of all tuples whose elements have same type.
+pub trait Tuple: Sized {
+    /// The type of elements.
+    type Item;
+    /// The tuple of the same length whose elements are `B`.
+    type Mapped<B>: Tuple<Item = B>;
+    /// The length of the tuple.
+    const ARITY: usize;
+
+    /// Same as `TupleMapN::by_ref`.
+    fn by_ref(&self) -> Self::Mapped<&Self::Item>;
+
+    /// Same as `TupleMapN::by_ref_mut`.
+    fn by_ref_mut(&mut self) -> Self::Mapped<&mut Self::Item>;
+
+    /// Same as `TupleMapN::fold`.
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B;
+
+    /// Same as `TupleMapN::map`.
+    fn map<B, F>(self, f: F) -> Self::Mapped<B>
+    where
+        F: FnMut(Self::Item) -> B;
+
+    /// Same as `TupleMapN::zipf`.
+    fn zip_with<U, B, F>(self, other: Self::Mapped<U>, f: F) -> Self::Mapped<B>
+    where
+        F: FnMut(Self::Item, U) -> B;
+
+    /// Same as `TupleMapN::from_fn`.
+    fn from_fn<F>(f: F) -> Self
+    where
+        F: FnMut(usize) -> Self::Item;
+
+    /// Same as `TupleMapN::try_from_iter`.
+    fn try_from_iter<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self::Item>;
+}
+
+/// Checks if every element of `t` matches a predicate.
+/// # Example
+/// ```
+/// assert!(tuple_map::fns::all((3, 6, 9), |x| x % 3 == 0));
+/// ```
+pub fn all<T, F>(t: T, mut f: F) -> bool
+where
+    T: Tuple,
+    F: FnMut(T::Item) -> bool,
+{
+    t.fold(true, |acc, x| acc && f(x))
+}
+
+/// Checks if any element of `t` matches a predicate.
+/// # Example
+/// ```
+/// assert!(tuple_map::fns::any((3, 4, 5), |x| x % 2 == 0));
+/// ```
+pub fn any<T, F>(t: T, mut f: F) -> bool
+where
+    T: Tuple,
+    F: FnMut(T::Item) -> bool,
+{
+    t.fold(false, |acc, x| acc || f(x))
+}
+
+/// Applies `f` to all elements of `t` and produce single value.
+/// # Example
+/// ```
+/// assert_eq!(tuple_map::fns::fold((3, 4, 5), 0, |sum, x| sum + x), 12);
+/// ```
+pub fn fold<T, B, F>(t: T, init: B, f: F) -> B
+where
+    T: Tuple,
+    F: FnMut(B, T::Item) -> B,
+{
+    t.fold(init, f)
+}
+
+/// Applies `f` to all elements of `t`.
+/// # Example
+/// ```
+/// let mut sum = 0;
+/// tuple_map::fns::for_each((3, 4, 5), |x| sum += x);
+/// assert_eq!(sum, 12);
+/// ```
+pub fn for_each<T, F>(t: T, mut f: F)
+where
+    T: Tuple,
+    F: FnMut(T::Item),
+{
+    t.fold((), |(), x| f(x))
+}
+
+/// Builds a tuple by calling `f` with each index in order.
+/// # Example
+/// ```
+/// let a: (usize, usize, usize) = tuple_map::fns::from_fn(|i| i * 2);
+/// assert_eq!(a, (0, 2, 4));
+/// ```
+pub fn from_fn<T, F>(f: F) -> T
+where
+    T: Tuple,
+    F: FnMut(usize) -> T::Item,
+{
+    T::from_fn(f)
+}
+
+/// Converts `t` into `Vec`.
+/// # Example
+/// ```
+/// assert_eq!(tuple_map::fns::into_vec((3, 4, 5)), vec![3, 4, 5]);
+/// ```
+pub fn into_vec<T: Tuple>(t: T) -> Vec<T::Item> {
+    t.fold(Vec::new(), |mut v, x| {
+        v.push(x);
+        v
+    })
+}
+
+/// Returns the length of `t`.
+/// # Example
+/// ```
+/// assert_eq!(tuple_map::fns::len(&(3, 4, 5)), 3);
+/// assert_eq!(tuple_map::fns::len(&[3, 4]), 2);
+/// ```
+pub fn len<T: Tuple>(_t: &T) -> usize {
+    T::ARITY
+}
+
+/// Applies `f` to all elements of `t` and returns the tuple of results.
+/// # Example
+/// ```
+/// assert_eq!(tuple_map::fns::map((3, 4, 5), |x| x * 2), (6, 8, 10));
+/// ```
+pub fn map<T, B, F>(t: T, f: F) -> T::Mapped<B>
+where
+    T: Tuple,
+    F: FnMut(T::Item) -> B,
+{
+    t.map(f)
+}
+
+/// Returns the product of all elements of `t`.
+/// # Example
+/// ```
+/// assert_eq!(tuple_map::fns::product((3, 4, 5)), 60);
+/// ```
+pub fn product<T>(t: T) -> T::Item
+where
+    T: Tuple,
+    T::Item: ::std::ops::MulAssign,
+{
+    // Tuples have at least one element, so the accumulator is always `Some` at the end.
+    t.fold(None, |acc, x| match acc {
+        Some(mut acc) => {
+            acc *= x;
+            Some(acc)
+        }
+        None => Some(x),
+    })
+    .unwrap()
+}
+
+/// Returns the sum of all elements of `t`.
+/// # Example
+/// ```
+/// assert_eq!(tuple_map::fns::sum((3, 4, 5)), 12);
+/// ```
+pub fn sum<T>(t: T) -> T::Item
+where
+    T: Tuple,
+    T::Item: ::std::ops::AddAssign,
+{
+    // Tuples have at least one element, so the accumulator is always `Some` at the end.
+    t.fold(None, |acc, x| match acc {
+        Some(mut acc) => {
+            acc += x;
+            Some(acc)
+        }
+        None => Some(x),
+    })
+    .unwrap()
+}
+
+/// Zips `a` and `b` into the tuple of pairs.
+/// # Example
+/// ```
+/// assert_eq!(tuple_map::fns::zip((3, 4), ('a', 'b')), ((3, 'a'), (4, 'b')));
+/// ```
+pub fn zip<T, U>(a: T, b: T::Mapped<U>) -> T::Mapped<(T::Item, U)>
+where
+    T: Tuple,
+{
+    a.zip_with(b, |x, y| (x, y))
+}
+
+/// Applies `f` to each pair of elements of `a` and `b`, and returns the tuple of results.
+/// # Example
+/// ```
+/// assert_eq!(tuple_map::fns::zip_with((3, 4), (5, 6), |x, y| x * y), (15, 24));
+/// ```
+pub fn zip_with<T, U, B, F>(a: T, b: T::Mapped<U>, f: F) -> T::Mapped<B>
+where
+    T: Tuple,
+    F: FnMut(T::Item, U) -> B,
+{
+    a.zip_with(b, f)
+}
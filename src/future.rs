@@ -0,0 +1,48 @@
+//! Futures returned by async methods of tuples, enabled by `futures` feature.
+use futures::future::FusedFuture;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Future returned by
+/// [`TupleMap2::select_first`](../trait.TupleMap2.html#method.select_first).
+///
+/// Resolves to the output of the first completed future and its index in the tuple.
+/// The remaining futures are dropped.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SelectFirst<F> {
+    futures: Vec<Pin<Box<F>>>,
+}
+
+impl<F: Future> SelectFirst<F> {
+    pub(crate) fn new(futures: Vec<Pin<Box<F>>>) -> Self {
+        SelectFirst { futures }
+    }
+}
+
+impl<F: Future> Future for SelectFirst<F> {
+    type Output = (F::Output, usize);
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let ready = self
+            .futures
+            .iter_mut()
+            .enumerate()
+            .find_map(|(i, f)| match f.as_mut().poll(cx) {
+                Poll::Ready(x) => Some((x, i)),
+                Poll::Pending => None,
+            });
+        match ready {
+            Some(out) => {
+                self.futures.clear();
+                Poll::Ready(out)
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl<F: Future> FusedFuture for SelectFirst<F> {
+    fn is_terminated(&self) -> bool {
+        self.futures.is_empty()
+    }
+}
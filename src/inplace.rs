@@ -0,0 +1,65 @@
+//! In-place access to tuple elements through mutable references, used by methods like
+//! `rotate_left_mut` or `get_many_mut`.
+use std::mem;
+
+use error::GetManyMutError;
+
+/// Picks `refs[indices[0]], refs[indices[1]], ...`, checking that all indices are in bounds
+/// and distinct.
+pub(crate) fn get_many_mut<T, const L: usize, const N: usize>(
+    refs: [&mut T; L],
+    indices: [usize; N],
+) -> Result<[&mut T; N], GetManyMutError> {
+    for (k, &index) in indices.iter().enumerate() {
+        if index >= L {
+            return Err(GetManyMutError::IndexOutOfBounds { index, len: L });
+        }
+        if indices[..k].contains(&index) {
+            return Err(GetManyMutError::OverlappingIndices { index });
+        }
+    }
+    let mut refs = refs.map(Some);
+    Ok(::std::array::from_fn(|k| refs[indices[k]].take().unwrap()))
+}
+
+/// Swaps the values pointed by `elems[i]` and `elems[j]`.
+///
+/// Panics if `i` or `j` is out of bounds.
+pub(crate) fn swap<T>(elems: &mut [&mut T], i: usize, j: usize) {
+    let len = elems.len();
+    assert!(
+        i < len && j < len,
+        "swap indices ({}, {}) out of bounds for a tuple of length {}",
+        i,
+        j,
+        len
+    );
+    if i == j {
+        return;
+    }
+    let (lo, hi) = if i < j { (i, j) } else { (j, i) };
+    let (left, right) = elems.split_at_mut(hi);
+    mem::swap(&mut *left[lo], &mut *right[0]);
+}
+
+/// Reverses the order of the values pointed by `elems`.
+pub(crate) fn reverse<T>(elems: &mut [&mut T]) {
+    let len = elems.len();
+    for i in 0..len / 2 {
+        swap(elems, i, len - 1 - i);
+    }
+}
+
+/// Rotates the values pointed by `elems` to the left by `k`, cyclically.
+pub(crate) fn rotate_left<T>(elems: &mut [&mut T], k: usize) {
+    let k = k % elems.len();
+    reverse(&mut elems[..k]);
+    reverse(&mut elems[k..]);
+    reverse(elems);
+}
+
+/// Rotates the values pointed by `elems` to the right by `k`, cyclically.
+pub(crate) fn rotate_right<T>(elems: &mut [&mut T], k: usize) {
+    let len = elems.len();
+    rotate_left(elems, len - k % len);
+}
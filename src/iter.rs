@@ -0,0 +1,122 @@
+//! Interoperation between iterators and tuples, like
+//! [`TupleConvert2::into_tuple_iter`](../trait.TupleConvert2.html#method.into_tuple_iter).
+
+use std::iter::FusedIterator;
+
+use fns::Tuple;
+
+/// Extends iterators with [`collect_tuple`](#method.collect_tuple).
+/// # Example
+/// ```
+/// # use tuple_map::iter::CollectTuple;
+/// let a: Option<(i32, i32, i32)> = (1..4).collect_tuple();
+/// assert_eq!(a, Some((1, 2, 3)));
+/// ```
+pub trait CollectTuple: Iterator + Sized {
+    /// Collects all items into a tuple, or returns `None` if the number of items
+    /// doesn't match the length of the tuple.
+    fn collect_tuple<U>(self) -> Option<U>
+    where
+        U: Tuple<Item = Self::Item>,
+    {
+        U::try_from_iter(self)
+    }
+}
+
+impl<I: Iterator> CollectTuple for I {}
+
+/// An iterator over the elements of a tuple, returned by
+/// [`TupleConvert2::into_tuple_iter`](../trait.TupleConvert2.html#method.into_tuple_iter),
+/// [`TupleConvert2::iter`](../trait.TupleConvert2.html#method.iter) or
+/// [`TupleConvert2::iter_mut`](../trait.TupleConvert2.html#method.iter_mut).
+#[derive(Clone, Debug)]
+pub struct TupleIter<T, const N: usize> {
+    inner: ::std::array::IntoIter<T, N>,
+}
+
+impl<T, const N: usize> TupleIter<T, N> {
+    pub(crate) fn new(elems: [T; N]) -> Self {
+        TupleIter {
+            inner: IntoIterator::into_iter(elems),
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for TupleIter<T, N> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        self.inner.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for TupleIter<T, N> {
+    fn next_back(&mut self) -> Option<T> {
+        self.inner.next_back()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for TupleIter<T, N> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<T, const N: usize> FusedIterator for TupleIter<T, N> {}
+
+/// An iterator over all orderings of the elements of a tuple, returned by
+/// [`TupleMap2::permutations`](../trait.TupleMap2.html#method.permutations).
+#[derive(Clone, Debug)]
+pub struct Permutations<U: Tuple> {
+    items: Vec<U::Item>,
+    indices: Option<Vec<usize>>,
+}
+
+impl<U: Tuple> Permutations<U> {
+    pub(crate) fn new(items: Vec<U::Item>) -> Self {
+        let indices = (0..items.len()).collect();
+        Permutations {
+            items,
+            indices: Some(indices),
+        }
+    }
+}
+
+impl<U> Iterator for Permutations<U>
+where
+    U: Tuple,
+    U::Item: Clone,
+{
+    type Item = U;
+    fn next(&mut self) -> Option<U> {
+        let indices = self.indices.as_mut()?;
+        let items = &self.items;
+        let res = U::from_fn(|k| items[indices[k]].clone());
+        // Advances `indices` to the next permutation in lexicographic order.
+        let len = indices.len();
+        let mut i = len - 1;
+        while i > 0 && indices[i - 1] > indices[i] {
+            i -= 1;
+        }
+        if i == 0 {
+            self.indices = None;
+        } else {
+            let mut j = len - 1;
+            while indices[j] < indices[i - 1] {
+                j -= 1;
+            }
+            indices.swap(i - 1, j);
+            indices[i..].reverse();
+        }
+        Some(res)
+    }
+}
+
+impl<U> FusedIterator for Permutations<U>
+where
+    U: Tuple,
+    U::Item: Clone,
+{
+}
@@ -40,17 +40,102 @@
 //! **Notes**
 //! This library defines different trait depending on the length of tuple,
 //! like `TupleMap1`, `TupleMap2`,..., by macro, so same docs are generated for each trait.
+//!
+//! Methods are grouped into three families for each length:
+//! - `TupleMapN` has iterator-like methods (`map`, `fold`, `find`, `zip`, ...).
+//! - `TupleArithN` has arithmetic methods (`add`, `sum`, `midpoint`, ...).
+//! - `TupleConvertN` has conversion methods (`into_vec`, `join`, `unwrap_all`, ...).
+//!
+//! All of them are re-exported from the crate root and from `tuple_map::prelude`.
+//! To write code generic over the length, use [`fns::Tuple`](fns/trait.Tuple.html), a single
+//! trait for all lengths with a generic associated type for mapped tuples.
+//! It is also implemented for arrays `[T; N]`, so such code works with both tuples and arrays.
+//! To map tuples whose elements have different types, use [`poly`](poly/index.html).
+
+#[cfg(feature = "either")]
+extern crate either;
+#[cfg(feature = "futures")]
+extern crate futures;
+#[cfg(feature = "glam")]
+extern crate glam;
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
+#[cfg(feature = "ndarray")]
+extern crate ndarray;
+#[cfg(feature = "proptest")]
+extern crate proptest;
+#[cfg(feature = "quickcheck")]
+extern crate quickcheck;
+
+/// Replaces any token tree by the given type.
+macro_rules! replace_ty {
+    ($_t: tt $sub: ty) => {
+        $sub
+    };
+}
+
+/// Replaces any token tree by the given expression.
+macro_rules! replace_expr {
+    ($_t: tt $sub: expr) => {
+        $sub
+    };
+}
+
+#[cfg(feature = "quickcheck")]
+pub mod arbitrary;
+pub mod arity;
+pub mod cell;
+pub mod error;
+pub mod fns;
+#[cfg(feature = "futures")]
+pub mod future;
+mod inplace;
+pub mod iter;
+#[cfg(feature = "ndarray")]
+pub mod ndim;
+pub mod num;
+pub mod poly;
+pub mod prelude;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+pub mod sync;
+pub mod unwrap;
+#[cfg(any(feature = "glam", feature = "nalgebra"))]
+pub mod vector;
+
+/// Statistics of a tuple, returned by `summary` method.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TupleSummary<T> {
+    /// The minimum element.
+    pub min: T,
+    /// The maximum element.
+    pub max: T,
+    /// The sum of all elements.
+    pub sum: T,
+    /// The arithmetic mean of all elements.
+    pub mean: f64,
+    /// The number of elements.
+    pub count: usize,
+}
+
 macro_rules! impl_tuple_map {
     ($trait: ident,
+     $arith: ident,
+     $convert: ident,
      $($name_reduced: ident)*,
      $($name: ident)+,
      $($name2: ident)+,
      $($item: ident)+,
      $($self: ident)+,
-     $($other: ident)+) => {
+     $($other: ident)+,
+     $($idx: tt)+) => {
+        /// Iterator-like methods for tuples: mapping, folding, searching and zipping.
         pub trait $trait {
             type Item;
 
+            /// The length of the tuple.
+            const ARITY: usize = 0 $(+ replace_expr!($idx 1))*;
+
             /// Checks if every element of tuple matches a predicate, like
             /// [`std::iter::Iterator::all`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.all).
             /// 
@@ -83,6 +168,37 @@ macro_rules! impl_tuple_map {
             where
                 F: FnMut(Self::Item) -> bool;
 
+            /// Binary searches the sorted tuple for `x`, like
+            /// [`slice::binary_search`](https://doc.rust-lang.org/std/primitive.slice.html#method.binary_search).
+            ///
+            /// If `x` is found, returns `Ok` with its index, otherwise returns `Err` with
+            /// the index where `x` could be inserted while keeping the order.
+            /// # Example
+            /// ```ignore
+            /// let a = (1, 3, 5, 7, ...);
+            /// assert_eq!(a.binary_search(&5), Ok(2));
+            /// assert_eq!(a.binary_search(&4), Err(2));
+            /// ```
+            fn binary_search(&self, x: &Self::Item) -> Result<usize, usize>
+            where
+                Self::Item: Ord,
+            {
+                self.binary_search_by(|y| y.cmp(x))
+            }
+
+            /// Binary searches the sorted tuple with a comparator function, like
+            /// [`slice::binary_search_by`](https://doc.rust-lang.org/std/primitive.slice.html#method.binary_search_by).
+            ///
+            /// `f` should return the order of an element relative to the target.
+            /// # Example
+            /// ```ignore
+            /// let a = (1, 3, 5, 7, ...);
+            /// assert_eq!(a.binary_search_by(|y| y.cmp(&7)), Ok(3));
+            /// ```
+            fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+            where
+                F: FnMut(&Self::Item) -> ::std::cmp::Ordering;
+
             /// Takes `&(a, a, a, ...)` and returns `(&a, &a, &a, ...)`
             /// # Examples
             /// ```ignore
@@ -99,6 +215,16 @@ macro_rules! impl_tuple_map {
             /// ```
             fn by_ref_mut(&mut self) -> ($(&mut Self::$item, )*);
 
+            /// Calls `f` with the elements of `(a, b, c, ...)` as its arguments, i.e. `f(a, b, c, ...)`.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5);
+            /// assert_eq!(a.call(|x, y, z| x * y + z), 17);
+            /// ```
+            fn call<R, F>(self, f: F) -> R
+            where
+                F: FnOnce($(Self::$item,)*) -> R;
+
             /// Takes `&(a, a, a, ...)` and returns `(a, a, a, ...)` 
             /// # Examples
             /// ```ignore
@@ -112,6 +238,125 @@ macro_rules! impl_tuple_map {
                 self.by_ref().map(|x| x.clone())
             }
 
+            /// Takes `(a, a, ...)` and `(b, b, ...)` of any length, then returns `(a, a, ..., b, b, ...)`.
+            /// The total length must be at most 16.
+            /// # Example
+            /// ```ignore
+            /// let a = (1, 2);
+            /// assert_eq!(a.concat((3, 4, 5)), (1, 2, 3, 4, 5));
+            /// ```
+            fn concat<U>(self, other: U) -> <Self as $crate::arity::Append<U>>::Output
+            where
+                Self: $crate::arity::Append<U> + Sized,
+            {
+                $crate::arity::Append::append(self, other)
+            }
+
+            /// Returns how many elements satisfy `f`.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, 6, ...);
+            /// assert_eq!(a.count_matches(|&x| x % 2 == 0), 2);
+            /// ```
+            fn count_matches<F>(self, mut f: F) -> usize
+            where
+                F: FnMut(&Self::Item) -> bool,
+                Self: Sized,
+            {
+                self.fold(0, |cnt, x| if f(&x) { cnt + 1 } else { cnt })
+            }
+
+            /// Builds `(T::default(), T::default(), ...)`.
+            /// # Example
+            /// ```ignore
+            /// let a: (f64, f64, f64) = TupleMap3::default_tuple();
+            /// assert_eq!(a, (0.0, 0.0, 0.0));
+            /// ```
+            fn default_tuple() -> Self
+            where
+                Self: Sized,
+                Self::Item: Default,
+            {
+                Self::from_fn(|_| Default::default())
+            }
+
+            /// Converts `g` taking a tuple into a function taking the elements as separate arguments.
+            /// # Example
+            /// ```ignore
+            /// let f = <(i32, i32, i32)>::curry(|(x, y, z)| x * y + z);
+            /// assert_eq!(f(3, 4, 5), 17);
+            /// ```
+            fn curry<R, G>(g: G) -> impl Fn($(Self::$item,)*) -> R
+            where
+                G: Fn(Self) -> R,
+                Self: Sized;
+
+            /// Checks if the trailing elements of the tuple are equal to `suffix`, which is
+            /// a shorter(or the same length) tuple, a slice, or an array.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, ..., 8, 9);
+            /// assert!(a.ends_with(&(8, 9)));
+            /// assert!(a.ends_with(&[9]));
+            /// assert!(!a.ends_with(&[8]));
+            /// ```
+            fn ends_with<P>(&self, suffix: &P) -> bool
+            where
+                P: $crate::arity::Affix<Self> + ?Sized,
+                Self: Sized,
+            {
+                suffix.is_suffix_of(self)
+            }
+
+            /// Takes `(a, a, a, ...)` and returns `((0, a), (1, a), (2, a), ...)`,
+            /// like [`std::iter::Iterator::enumerate`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.enumerate).
+            /// # Example
+            /// ```ignore
+            /// let a = ("a", "b", "c", ...);
+            /// assert_eq!(a.enumerate(), ((0, "a"), (1, "b"), (2, "c"), ...));
+            /// ```
+            fn enumerate(self) -> ($((usize, Self::$item),)*);
+
+            /// Takes a predicate `f` and returns all elements which satisfy it as `Vec`,
+            /// like [`std::iter::Iterator::filter`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.filter).
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, 6, ...);
+            /// assert_eq!(a.filter(|&x| x % 2 == 0), vec![4, 6, ...]);
+            /// ```
+            fn filter<F>(self, mut f: F) -> Vec<Self::Item>
+            where
+                F: FnMut(&Self::Item) -> bool,
+                Self: Sized,
+            {
+                self.fold(Vec::new(), |mut v, x| {
+                    if f(&x) {
+                        v.push(x);
+                    }
+                    v
+                })
+            }
+
+            /// Takes a closure `f` and returns all `Some` values it produces as `Vec`,
+            /// like [`std::iter::Iterator::filter_map`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.filter_map).
+            /// # Example
+            /// ```ignore
+            /// let a = ("3", "x", "5", ...);
+            /// assert_eq!(a.filter_map(|s| s.parse::<i32>().ok()), vec![3, 5, ...]);
+            /// ```
+            fn filter_map<B, F>(self, mut f: F) -> Vec<B>
+            where
+                F: FnMut(Self::Item) -> Option<B>,
+                Self: Sized,
+            {
+                self.fold(Vec::new(), |mut v, x| {
+                    if let Some(y) = f(x) {
+                        v.push(y);
+                    }
+                    v
+                })
+            }
+
             /// Find the leftest element which satisfies `f` and returns it.
             /// # Example
             /// ```ignore
@@ -125,6 +370,21 @@ macro_rules! impl_tuple_map {
             where
                 F: FnMut(&Self::Item) -> bool;
 
+            /// Takes `((a, a, ...), (a, a, ...), ...)` and returns `(a, a, ..., a, a, ...)`.
+            /// All inner tuples must have the same length, and the total length must be at most 16.
+            /// This is the inverse of `TupleMapEven::chunks2` for pairs.
+            /// # Example
+            /// ```ignore
+            /// let a = ((1, 2), (3, 4), ...);
+            /// assert_eq!(a.flatten(), (1, 2, 3, 4, ...));
+            /// ```
+            fn flatten(self) -> <Self as $crate::arity::Flatten>::Output
+            where
+                Self: $crate::arity::Flatten + Sized,
+            {
+                $crate::arity::Flatten::into_flat(self)
+            }
+
             /// Takes a closure `f` and applies it to all elements to tuple, and produce single value.
             /// This is similar to [`std::iter::Iterator::fold`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.fold)
             /// # Example
@@ -136,6 +396,17 @@ macro_rules! impl_tuple_map {
             where
                 F: FnMut(B, Self::Item) -> B;
 
+            /// Same as `fold`, but `f` also takes the index of each element.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, ...);
+            /// let weighted = a.fold_with_index(0, |sum, i, x| sum + i * x);
+            /// assert_eq!(weighted, 0 * 3 + 1 * 4 + 2 * 5 + ...);
+            /// ```
+            fn fold_with_index<B, F>(self, init: B, f: F) -> B
+            where
+                F: FnMut(B, usize, Self::Item) -> B;
+
             /// Takes a closure `f` and applies it to all elements to tuple.
             /// `f` can cause side effect(because it's `FnMut`), but this method return nothing.
             /// Similar to [`std::iter::Iterator::for_each`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.for_each)
@@ -149,570 +420,3557 @@ macro_rules! impl_tuple_map {
             where
                 F: FnMut(Self::Item) -> ();
 
-            /// return Self.
-            /// It's not intended to used by user.
-            fn id(self) -> ($(Self::$item,)*);
-            
-            /// Convert tuple into Vec.
+            /// Takes a closure `f` and applies it to all elements with their indices,
+            /// like `for_each`.
             /// # Example
             /// ```ignore
-            /// let a = (3, 4, 5, ...)
-            /// assert_eq(a.into_vec(), vec![3, 4, 5, ...])
+            /// let a = ("a", "b", "c", ...);
+            /// a.for_each_with_index(|i, x| println!("{}: {}", i, x));
             /// ```
-            fn into_vec(self) -> Vec<Self::Item>;
+            fn for_each_with_index<F>(self, f: F)
+            where
+                F: FnMut(usize, Self::Item);
 
-            /// Takes a closure `f` and (a, a, a, ...), then returns (f(a), f(a), f(a), ...).
-            /// Similar to [`std::iter::Iterator::map`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.map).
+            /// Builds `(f(0), f(1), f(2), ...)` by calling `f` with each index in order,
+            /// like [`std::array::from_fn`](https://doc.rust-lang.org/std/array/fn.from_fn.html).
             /// # Example
             /// ```ignore
-            /// let a = (3, 4, 5, ...);
-            /// assert_eq!(a.map(|x| x * 2), (6, 8, 10, ...));
+            /// let a: (usize, usize, usize) = TupleMap3::from_fn(|i| i * 2);
+            /// assert_eq!(a, (0, 2, 4));
             /// ```
-            fn map<B, F>(self, f: F) -> ($($other, )*)
+            fn from_fn<F>(f: F) -> Self
             where
-                F: FnMut(Self::Item) -> B;
+                Self: Sized,
+                F: FnMut(usize) -> Self::Item;
 
-            /// return nth element in the tuple.
+            /// Returns a reference to the `i`th element, or `None` if `i` is out of bounds.
+            /// Unlike `nth`, this doesn't consume the tuple.
             /// # Example
             /// ```ignore
-            /// let a = (3, 4, 5, ..);
-            /// assert_eq!(a.nth(2), Some(5));
+            /// let a = (3, 4, 5, ...);
+            /// assert_eq!(a.get(1), Some(&4));
+            /// assert_eq!(a.get(100), None);
             /// ```
-            fn nth(self, i: usize) -> Option<Self::Item>;
+            fn get(&self, i: usize) -> Option<&Self::Item> {
+                self.by_ref().nth(i)
+            }
 
-            /// Checks if all elements of the tuple is same.
+            /// Takes `&mut (a, a, a, ...)` and returns mutable references to the elements
+            /// at the given distinct indices, like `slice::get_disjoint_mut`.
+            /// Returns an error if any index is out of range or requested twice.
             /// # Example
             /// ```ignore
-            /// let a = (3, 3, 3, ...);
-            /// assert!(a.same());
+            /// let mut a = (3, 4, 5, ...);
+            /// let [x, y] = a.get_many_mut([0, 2]).unwrap();
+            /// ::std::mem::swap(x, y);
+            /// assert_eq!(a, (5, 4, 3, ...));
+            /// assert!(a.get_many_mut([1, 1]).is_err());
             /// ```
-            fn same(self) -> bool
-            where
-                Self::Item: PartialEq;
+            fn get_many_mut<const N: usize>(
+                &mut self,
+                indices: [usize; N],
+            ) -> Result<[&mut Self::Item; N], $crate::error::GetManyMutError>;
 
-            /// Takes i and checks if all elements of the tuple is same as i.
+            /// Returns a mutable reference to the `i`th element, or `None` if `i` is out of bounds.
             /// # Example
             /// ```ignore
-            /// let a = (3, 3, 3, ...);
-            /// assert!(a.same_as(3));
+            /// let mut a = (3, 4, 5, ...);
+            /// if let Some(x) = a.get_mut(1) {
+            ///     *x = 10;
+            /// }
+            /// assert_eq!(a, (3, 10, 5, ...));
             /// ```
-            fn same_as(self, i: Self::Item) -> bool
-            where
-                Self::Item: PartialEq;
+            fn get_mut(&mut self, i: usize) -> Option<&mut Self::Item> {
+                self.by_ref_mut().nth(i)
+            }
 
-            /// Takes `(a, b, c, ...)` then returns `a + b + c ...`
-            fn sum(self) -> Self::Item
-            where
-                 Self::Item: ::std::ops::AddAssign;
+            /// return Self.
+            /// It's not intended to used by user.
+            fn id(self) -> ($(Self::$item,)*);
 
-            /// Takes `(a, b, c, ...)` then returns `a + b + c ...`
-            fn product(self) -> Self::Item
+            /// Takes `(a, b, c, ...)` and `x`, then inserts `x` as the `I`th element.
+            /// `I` is checked at compile time, so it must not exceed the length.
+            /// The length of `self` must be less than 16.
+            /// # Example
+            /// ```ignore
+            /// let a = (1, 3, ...);
+            /// assert_eq!(a.insert_at::<1>(2), (1, 2, 3, ...));
+            /// ```
+            fn insert_at<const I: usize>(
+                self,
+                x: Self::Item,
+            ) -> <Self as $crate::arity::InsertAt<I, Self::Item>>::Output
             where
-                 Self::Item: ::std::ops::MulAssign;
+                Self: $crate::arity::InsertAt<I, Self::Item> + Sized,
+            {
+                $crate::arity::InsertAt::insert_tuple(self, x)
+            }
 
-            /// Takes `(a, b, c, ...)` then returns the maximum value of tuple.
-            /// This method is named `tmax` instead of `max`, to avoid overlap
-            /// to `std::cmp::ord::max`.
-            fn tmax(self) -> Self::Item
+            /// Calls `f` with a reference to each element and returns the tuple unchanged,
+            /// like [`std::iter::Iterator::inspect`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.inspect).
+            /// Useful for debugging in the middle of method chains.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, ...);
+            /// let b = a.inspect(|x| println!("{}", x)).map(|x| x * 2);
+            /// assert_eq!(b, (6, 8, 10, ...));
+            /// ```
+            fn inspect<F>(self, mut f: F) -> ($(Self::$item,)*)
             where
-                Self::Item: ::std::cmp::PartialOrd;
+                F: FnMut(&Self::Item),
+                Self: Sized,
+            {
+                self.map(|x| {
+                    f(&x);
+                    x
+                })
+            }
 
-            /// Takes `(a, b, c, ...)` then returns the minimum value of tuple.
-            /// This method is named `tmin` instead of `min`, to avoid overlap
-            /// to `std::cmp::ord::min`.
-            fn tmin(self) -> Self::Item
-            where
-                Self::Item: ::std::cmp::PartialOrd;
-            
-            /// Takes `(a, a, a, ...)` and `(b, b, b, ...)` then returns `((a, b), (a, b), (a, b), ...)` 
+            /// Builds `(start, start + step, start + step + step, ...)`.
             /// # Example
             /// ```ignore
-            /// let a = (3, 4, 5, ...);
-            /// let b = ('a', 'b', 'c', ...);
-            /// assert_eq!(a.zip(b), ((3, 'a'), (4, 'b'), (5, 'c'), ...));
+            /// let a: (i32, i32, i32) = TupleMap3::iota(1, 2);
+            /// assert_eq!(a, (1, 3, 5));
             /// ```
-            fn zip<U, B>(self, other: U) -> ($((Self::$item, $other),)*)
+            fn iota(start: Self::Item, step: Self::Item) -> Self
             where
-                U: $trait<Item = B>;
+                Self: Sized,
+                Self::Item: Clone + ::std::ops::Add<Output = Self::Item>,
+            {
+                let mut prev: Option<Self::Item> = None;
+                Self::from_fn(|_| {
+                    let cur = match prev.take() {
+                        Some(p) => p + step.clone(),
+                        None => start.clone(),
+                    };
+                    prev = Some(cur.clone());
+                    cur
+                })
+            }
 
-            /// Takes `(a, a, a, ...)` and `(b, b, b, ...)` and closure f,
-            /// then returns `(f(a, b), f(a, b), f(a, b), ...)` 
+            /// Returns `false`, since all tuples implementing this trait have at least one element.
+            /// This exists for consistency with `len`.
+            fn is_empty(&self) -> bool {
+                Self::ARITY == 0
+            }
+
+            /// Returns the length of the tuple, i.e. `ARITY`.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5);
+            /// assert_eq!(a.len(), 3);
+            /// ```
+            fn len(&self) -> usize {
+                Self::ARITY
+            }
+
+            /// Takes a closure `f` and (a, a, a, ...), then returns (f(a), f(a), f(a), ...).
+            /// Similar to [`std::iter::Iterator::map`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.map).
             /// # Example
             /// ```ignore
             /// let a = (3, 4, 5, ...);
-            /// let b = ('a', 'b', 'c', ...);
-            /// assert_eq!(
-            ///     a.zipf(b, |x, y| format!("{}{}", x, y)),
-            ///     ("3a", "4b", "5c", ...).map(|x| x.to_owned())
-            /// );
+            /// assert_eq!(a.map(|x| x * 2), (6, 8, 10, ...));
             /// ```
-            fn zipf<U, I, F, B>(self, other: U, f: F) -> ($($other,)*)
+            fn map<B, F>(self, f: F) -> ($($other, )*)
             where
-                U: $trait<Item = I>,
-                F: FnMut(Self::Item, I) -> B;
+                F: FnMut(Self::Item) -> B;
 
-            /// Takes `(a, a, a, ...)` and `(b, b, b, ...)`,
-            /// then returns `(a + b, a + b, a + b, ...)` 
+            /// Applies `f` only to the first element of `(a, b, c, ...)`.
             /// # Example
             /// ```ignore
             /// let a = (3, 4, 5, ...);
-            /// let b = (7, 8, 9, ....)
-            /// assert_eq!(a.add(b), (10, 12, 14, ...));
+            /// assert_eq!(a.map_first(|x| x * 10), (30, 4, 5, ...));
             /// ```
-            fn add<U, I, B>(self, other: U) -> ($($other,)*)
+            fn map_first<F>(self, f: F) -> ($(Self::$item,)*)
             where
-                U: $trait<Item = I>,
-                Self::Item: ::std::ops::Add<I, Output = B>,
+                F: FnOnce(Self::Item) -> Self::Item,
                 Self: Sized,
             {
-                self.zipf(other, |a, b| a + b)
+                self.map_nth(0, f)
             }
 
-            /// Takes `(a, a, a, ...)` and `(b, b, b, ...)`,
-            /// then returns `(a - b, a - b, a - b, ...)` 
+            /// Applies `f` only to the last element of `(a, b, c, ...)`.
             /// # Example
             /// ```ignore
-            /// let a = (7, 8, 9, ....);
-            /// let b = (3, 4, 5, ....);
-            /// assert!(a.sub(b).same());
+            /// let a = (3, 4, 5, ...);
+            /// assert_eq!(a.map_last(|x| x * 10), (3, 4, ..., 50));
             /// ```
-            fn sub<U, I, B>(self, other: U) -> ($($other,)*)
+            fn map_last<F>(self, f: F) -> ($(Self::$item,)*)
             where
-                U: $trait<Item = I>,
-                Self::Item: ::std::ops::Sub<I, Output = B>,
+                F: FnOnce(Self::Item) -> Self::Item,
                 Self: Sized,
             {
-                self.zipf(other, |a, b| a - b)
+                self.map_nth(Self::ARITY - 1, f)
             }
 
-            /// Takes `(a, a, a, ...)` and `(b, b, b, ...)`,
-            /// then returns `(a * b, a * b, a * b, ...)` 
+            /// Applies `f` only to the `i`th element of `(a, b, c, ...)`, leaving the others untouched.
+            /// If `i` is out of bounds, the tuple is returned as is.
             /// # Example
             /// ```ignore
-            /// let a = (7, 8, 9, ....);
-            /// let b = (3, 4, 5, ....);
-            /// assert_eq!(a.mul(b), (21, 32, 45, ...));
+            /// let a = (3, 4, 5, ...);
+            /// assert_eq!(a.map_nth(1, |x| x * 10), (3, 40, 5, ...));
             /// ```
-            fn mul<U, I, B>(self, other: U) -> ($($other,)*)
+            fn map_nth<F>(self, i: usize, f: F) -> ($(Self::$item,)*)
             where
-                U: $trait<Item = I>,
-                Self::Item: ::std::ops::Mul<I, Output = B>,
+                F: FnOnce(Self::Item) -> Self::Item,
                 Self: Sized,
             {
-                self.zipf(other, |a, b| a * b)
+                let mut f = Some(f);
+                self.map_with_index(|j, x| if j == i { f.take().unwrap()(x) } else { x })
             }
 
-            /// Takes `(a, a, a, ...)` and `(b, b, b, ...)`,
-            /// then returns `(a * b, a * b, a * b, ...)` 
+            /// Takes a closure `f` and `(a, a, a, ...)`, then returns `(f(0, a), f(1, a), f(2, a), ...)`.
             /// # Example
             /// ```ignore
-            /// let a = (6, 8, 10, ....);
-            /// let b = (3, 4, 5, ....);
-            /// assert!(a.div(b).same());
+            /// let a = (3, 4, 5, ...);
+            /// assert_eq!(a.map_with_index(|i, x| i * x), (0, 4, 10, ...));
             /// ```
-            fn div<U, I, B>(self, other: U) -> ($($other,)*)
+            fn map_with_index<B, F>(self, f: F) -> ($($other, )*)
             where
-                U: $trait<Item = I>,
-                Self::Item: ::std::ops::Div<I, Output = B>,
-                Self: Sized,
-            {
-                self.zipf(other, |a, b| a / b)
-            }
-        }
-        
-        impl<T> $trait for ($($self, )*) {
-            type Item = T;
+                F: FnMut(usize, Self::Item) -> B;
 
-            fn any<F>(self, mut f: F) -> bool
+            /// Takes `(a, b, c, ...)` and returns the tuple of length `M`, dropping trailing elements.
+            /// `M` is checked at compile time, so it must not exceed the length.
+            /// # Example
+            /// ```ignore
+            /// let a = (1.0, 2.0, 3.0);
+            /// assert_eq!(a.narrow::<2>(), (1.0, 2.0));
+            /// ```
+            fn narrow<const M: usize>(self) -> <Self as $crate::arity::SplitAt<M>>::Front
             where
-                F: FnMut(Self::Item) -> bool
+                Self: $crate::arity::SplitAt<M> + Sized,
             {
-                let ($($name,)*) = self;
-                $(if f($name) { return true } )*
-                false
-            }
-            
-            fn all<F>(self, mut f: F) -> bool
-            where
-                F: FnMut(Self::Item) -> bool
-            {
-                let ($($name,)*) = self;
-                $(if !f($name) { return false } )*
-                true
+                $crate::arity::SplitAt::split_tuple(self).0
             }
 
-            fn by_ref(&self) -> ($(&Self::$item, )*) {
-                let ($(ref $name,)*) = *self;
-                ($($name,)*)
-            }
+            /// return nth element in the tuple.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, ..);
+            /// assert_eq!(a.nth(2), Some(5));
+            /// ```
+            fn nth(self, i: usize) -> Option<Self::Item>;
 
-            fn by_ref_mut(&mut self) -> ($(&mut Self::$item, )*) {
-                let ($(ref mut $name,)*) = *self;
-                ($($name,)*)
-            }
+            /// Returns the `I`th element of the tuple, where `I` is checked at compile time.
+            /// Unlike `nth`, this returns the element directly without a runtime bounds check.
+            /// Using an out-of-range `I` is a compile error.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, ...);
+            /// assert_eq!(a.nth_const::<1>(), 4);
+            /// ```
+            fn nth_const<const I: usize>(self) -> Self::Item;
 
-            fn find<F>(self, mut f: F) -> Option<Self::Item>
+            /// Takes `(a, b, c, d, ...)` and returns the adjacent pairs `((a, b), (b, c), (c, d), ...)`,
+            /// whose length is one less than the original tuple.
+            /// # Example
+            /// ```ignore
+            /// let a = (1, 3, 6, 10, ...);
+            /// assert_eq!(a.pairwise().map(|(x, y)| y - x), (2, 3, 4, ...));
+            /// ```
+            fn pairwise(self) -> ($(replace_ty!($name_reduced (Self::Item, Self::Item)),)*)
             where
-                F: FnMut(&Self::Item) -> bool
+                Self::Item: Clone;
+
+            /// Splits elements into two `Vec`s: ones which satisfy `f` and ones which don't,
+            /// like [`std::iter::Iterator::partition`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.partition).
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, 6, ...);
+            /// let (even, odd) = a.partition(|&x| x % 2 == 0);
+            /// assert_eq!(even, vec![4, 6, ...]);
+            /// assert_eq!(odd, vec![3, 5, ...]);
+            /// ```
+            fn partition<F>(self, mut f: F) -> (Vec<Self::Item>, Vec<Self::Item>)
+            where
+                F: FnMut(&Self::Item) -> bool,
+                Self: Sized,
             {
-                let ($($name,)*) = self;
-                $(if f(&$name) { return Some($name) })*
-                None
+                self.fold((Vec::new(), Vec::new()), |(mut left, mut right), x| {
+                    if f(&x) {
+                        left.push(x);
+                    } else {
+                        right.push(x);
+                    }
+                    (left, right)
+                })
             }
-            
-            fn fold<B, F>(self, mut init: B, mut f: F) -> B
+
+            /// Applies `f` to each element and splits the results into `Left` values and
+            /// `Right` values, like `Itertools::partition_map`.
+            /// Enabled by `either` feature.
+            /// # Example
+            /// ```ignore
+            /// let a = ("3", "x", "5", ...);
+            /// let (nums, errs): (Vec<i32>, Vec<&str>) = a.partition_map(|s| match s.parse() {
+            ///     Ok(x) => Either::Left(x),
+            ///     Err(_) => Either::Right(s),
+            /// });
+            /// assert_eq!(nums, vec![3, 5, ...]);
+            /// assert_eq!(errs, vec!["x", ...]);
+            /// ```
+            #[cfg(feature = "either")]
+            fn partition_map<L, R, F>(self, mut f: F) -> (Vec<L>, Vec<R>)
             where
-                F: FnMut(B, Self::Item) -> B
+                F: FnMut(Self::Item) -> ::either::Either<L, R>,
+                Self: Sized,
             {
-                let ($($name,)*) = self;
-                $(init = f(init, $name);)*
-                init
+                self.fold((Vec::new(), Vec::new()), |(mut left, mut right), x| {
+                    match f(x) {
+                        ::either::Either::Left(l) => left.push(l),
+                        ::either::Either::Right(r) => right.push(r),
+                    }
+                    (left, right)
+                })
             }
-            
-            fn for_each<F>(self, mut f: F)
+
+            /// Returns an iterator over all orderings of `(a, b, c, ...)`, in lexicographic order
+            /// of the indices. The orderings are generated lazily, so taking the first few of them
+            /// is cheap even for long tuples.
+            /// # Example
+            /// ```ignore
+            /// let a = (1, 2, 3);
+            /// assert_eq!(
+            ///     a.permutations().collect::<Vec<_>>(),
+            ///     vec![(1, 2, 3), (1, 3, 2), (2, 1, 3), (2, 3, 1), (3, 1, 2), (3, 2, 1)]
+            /// );
+            /// ```
+            fn permutations(self) -> $crate::iter::Permutations<($(Self::$item,)*)>
             where
-                F: FnMut(Self::Item) -> ()
+                Self: Sized,
+                Self::Item: Clone,
             {
-                let ($($name,)*) = self;
-                $(f($name);)*
+                $crate::iter::Permutations::new(self.fold(Vec::new(), |mut v, x| {
+                    v.push(x);
+                    v
+                }))
             }
 
-            fn id(self) -> ($(Self::$item,)*) {
-                let ($($name,)*) = self;
-                ($($name,)*)
+            /// Takes `(..., x, y, z)` and returns `((..., x, y), z)`.
+            /// Same as [`unsnoc`](#tymethod.unsnoc), named as the inverse of
+            /// [`push_back`](#method.push_back).
+            /// # Example
+            /// ```ignore
+            /// let a = (1, 2).push_back(3);
+            /// assert_eq!(a.pop_back(), ((1, 2), 3));
+            /// ```
+            fn pop_back(self) -> (($(replace_ty!($name_reduced Self::Item),)*), Self::Item)
+            where
+                Self: Sized,
+            {
+                self.unsnoc()
             }
-            
-            fn into_vec(self) -> Vec<Self::Item> {
-                let ($($name,)*) = self;
-                let mut v = Vec::new();
-                $(v.push($name);)*
-                v
+
+            /// Takes `(a, b, c, ...)` and returns `(a, (b, c, ...))`.
+            /// Same as [`uncons`](#tymethod.uncons), named as the inverse of
+            /// [`push_front`](#method.push_front).
+            /// # Example
+            /// ```ignore
+            /// let a = (2, 3).push_front(1);
+            /// assert_eq!(a.pop_front(), (1, (2, 3)));
+            /// ```
+            fn pop_front(self) -> (Self::Item, ($(replace_ty!($name_reduced Self::Item),)*))
+            where
+                Self: Sized,
+            {
+                self.uncons()
             }
 
+            /// Returns the index of the leftest element which satisfies `f`,
+            /// like [`std::iter::Iterator::position`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.position).
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, 6, ...);
+            /// assert_eq!(a.position(|&x| x % 2 == 0), Some(1));
+            /// ```
+            fn position<F>(self, f: F) -> Option<usize>
+            where
+                F: FnMut(&Self::Item) -> bool;
 
-            fn nth(self, i: usize) -> Option<Self::Item> {
-                let ($($name,)*) = self;
-                let mut _cnt = 0;
-                $(if _cnt == i { return Some($name) } else { _cnt += 1 })*
-                None
-            }
-            
-            fn map<B, F>(self, mut f: F) -> ($($other, )*)
+            /// Takes `(a, b, ...)` and `x`, then returns `(a, b, ..., x)`.
+            /// The length of `self` must be less than 16.
+            /// # Example
+            /// ```ignore
+            /// let a = (1, 2);
+            /// assert_eq!(a.push_back(3), (1, 2, 3));
+            /// ```
+            fn push_back(self, x: Self::Item) -> <Self as $crate::arity::Append<(Self::Item,)>>::Output
             where
-                F: FnMut(Self::Item) -> B
+                Self: $crate::arity::Append<(Self::Item,)> + Sized,
             {
-                let ($($name,)*) = self;
-                ($(f($name),)*)
+                $crate::arity::Append::append(self, (x,))
             }
 
-            #[allow(unused_variables)]
-            fn same(self) -> bool
+            /// Takes `(a, b, ...)` and `x`, then returns `(x, a, b, ...)`.
+            /// The length of `self` must be less than 16.
+            /// # Example
+            /// ```ignore
+            /// let a = (1, 2);
+            /// assert_eq!(a.push_front(0), (0, 1, 2));
+            /// ```
+            fn push_front(self, x: Self::Item) -> <(Self::Item,) as $crate::arity::Append<Self>>::Output
             where
-                Self::Item: PartialEq
+                (Self::Item,): $crate::arity::Append<Self>,
+                Self: Sized,
             {
-                let (first, $($name_reduced,)*) = self;
-                $(if $name_reduced != first { return false } )*
-                true
+                $crate::arity::Append::append((x,), self)
             }
 
-            fn same_as(self, i: Self::Item) -> bool
+            /// Reduces the elements to a single one by repeatedly applying `f`, using the
+            /// first element as the initial value.
+            /// Unlike [`std::iter::Iterator::reduce`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.reduce),
+            /// this returns the element directly because a tuple is never empty.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 8, 5, ...);
+            /// assert_eq!(a.reduce(|x, y| if x > y { x } else { y }), 8);
+            /// ```
+            fn reduce<F>(self, f: F) -> Self::Item
             where
-                Self::Item: PartialEq
+                F: FnMut(Self::Item, Self::Item) -> Self::Item;
+
+            /// Removes the `I`th element of `(a, b, c, ...)` and returns it with the remaining tuple.
+            /// `I` is checked at compile time, so it must be less than the length.
+            /// # Example
+            /// ```ignore
+            /// let a = (1, 2, 3, ...);
+            /// assert_eq!(a.remove_at::<1>(), (2, (1, 3, ...)));
+            /// ```
+            fn remove_at<const I: usize>(
+                self,
+            ) -> (Self::Item, <Self as $crate::arity::RemoveAt<I, Self::Item>>::Output)
+            where
+                Self: $crate::arity::RemoveAt<I, Self::Item> + Sized,
             {
-                let ($($name,)*) = self;
-                $(if $name != i { return false })*
-                true
+                $crate::arity::RemoveAt::remove_tuple(self)
             }
 
-            #[allow(unused_mut)]
-            fn sum(self) -> Self::Item
+            /// Replaces the `i`th element with `value` and returns the old one,
+            /// or returns `None` if `i` is out of bounds.
+            /// # Example
+            /// ```ignore
+            /// let mut a = (3, 4, 5, ...);
+            /// assert_eq!(a.replace(1, 10), Some(4));
+            /// assert_eq!(a, (3, 10, 5, ...));
+            /// ```
+            fn replace(&mut self, i: usize, value: Self::Item) -> Option<Self::Item> {
+                self.get_mut(i).map(|x| ::std::mem::replace(x, value))
+            }
+
+            /// Takes `(a, b, c, ...)` and returns `(..., c, b, a)`.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, ...);
+            /// assert_eq!(a.rev(), (..., 5, 4, 3));
+            /// ```
+            fn rev(self) -> ($(Self::$item,)*);
+
+            /// Reverses the order of elements of `&mut (a, b, c, ...)` in place.
+            /// # Example
+            /// ```ignore
+            /// let mut a = (1, 2, 3);
+            /// a.reverse_mut();
+            /// assert_eq!(a, (3, 2, 1));
+            /// ```
+            fn reverse_mut(&mut self);
+
+            /// Find the rightest element which satisfies `f` and returns it,
+            /// like [`std::iter::Iterator::rfind`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.rfind).
+            /// Elements are checked from right to left.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, 6, 7, ...);
+            /// assert_eq!(a.rfind(|&x| x % 2 == 0), Some(6));
+            /// ```
+            fn rfind<F>(self, f: F) -> Option<Self::Item>
             where
-                Self::Item: ::std::ops::AddAssign
+                F: FnMut(&Self::Item) -> bool;
+
+            /// Same as `fold`, but applies `f` to elements from right to left,
+            /// like [`std::iter::DoubleEndedIterator::rfold`](https://doc.rust-lang.org/std/iter/trait.DoubleEndedIterator.html#method.rfold).
+            /// # Example
+            /// ```ignore
+            /// let a = ("a", "b", "c", ...);
+            /// let s = a.rfold(String::new(), |s, x| s + x);
+            /// assert_eq!(s, "...cba");
+            /// ```
+            fn rfold<B, F>(self, init: B, f: F) -> B
+            where
+                F: FnMut(B, Self::Item) -> B;
+
+            /// Takes `(a, b, c, ...)` and shifts elements to the left by `K`, cyclically.
+            /// # Example
+            /// ```ignore
+            /// let a = (1, 2, 3);
+            /// assert_eq!(a.rotate_left::<1>(), (2, 3, 1));
+            /// ```
+            fn rotate_left<const K: usize>(self) -> ($(Self::$item,)*);
+
+            /// Shifts elements of `&mut (a, b, c, ...)` to the left by `k`, cyclically, in place.
+            /// # Example
+            /// ```ignore
+            /// let mut a = (1, 2, 3);
+            /// a.rotate_left_mut(1);
+            /// assert_eq!(a, (2, 3, 1));
+            /// ```
+            fn rotate_left_mut(&mut self, k: usize);
+
+            /// Takes `(a, b, c, ...)` and shifts elements to the right by `K`, cyclically.
+            /// # Example
+            /// ```ignore
+            /// let a = (1, 2, 3);
+            /// assert_eq!(a.rotate_right::<1>(), (3, 1, 2));
+            /// ```
+            fn rotate_right<const K: usize>(self) -> ($(Self::$item,)*);
+
+            /// Shifts elements of `&mut (a, b, c, ...)` to the right by `k`, cyclically, in place.
+            /// # Example
+            /// ```ignore
+            /// let mut a = (1, 2, 3);
+            /// a.rotate_right_mut(1);
+            /// assert_eq!(a, (3, 1, 2));
+            /// ```
+            fn rotate_right_mut(&mut self, k: usize);
+
+            /// Returns the index of the rightest element which satisfies `f`,
+            /// like [`std::iter::Iterator::rposition`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.rposition).
+            /// Elements are checked from right to left.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, 6, 7, ...);
+            /// assert_eq!(a.rposition(|&x| x % 2 == 0), Some(3));
+            /// ```
+            fn rposition<F>(self, f: F) -> Option<usize>
+            where
+                F: FnMut(&Self::Item) -> bool;
+
+            /// Checks if all elements of the tuple is same.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 3, 3, ...);
+            /// assert!(a.same());
+            /// ```
+            fn same(self) -> bool
+            where
+                Self::Item: PartialEq;
+
+            /// Takes i and checks if all elements of the tuple is same as i.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 3, 3, ...);
+            /// assert!(a.same_as(3));
+            /// ```
+            fn same_as(self, i: Self::Item) -> bool
+            where
+                Self::Item: PartialEq;
+
+            /// Takes an initial state and a closure `f`, and returns `(f(&mut st, a), f(&mut st, a), ...)`
+            /// where the state is threaded through the elements from left to right,
+            /// like [`std::iter::Iterator::scan`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.scan).
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, ...);
+            /// let b = a.scan(0, |sum, x| {
+            ///     *sum += x;
+            ///     *sum
+            /// });
+            /// assert_eq!(b, (3, 7, 12, ...));
+            /// ```
+            fn scan<St, B, F>(self, init: St, mut f: F) -> ($($other, )*)
+            where
+                F: FnMut(&mut St, Self::Item) -> B,
+                Self: Sized,
             {
-                let (mut acc, $($name_reduced,)*) = self;
-                $(acc += $name_reduced;)*
-                acc
+                let mut state = init;
+                self.map(|x| f(&mut state, x))
             }
 
-            #[allow(unused_mut)]
-            fn product(self) -> Self::Item
+            /// Builds `(x_I0, x_I1)` by cloning the elements at the given indices, like swizzling of
+            /// vectors in shading languages. The indices are checked at compile time and may repeat.
+            /// # Example
+            /// ```ignore
+            /// let a = (1, 2, 3, ...);
+            /// assert_eq!(a.select2::<2, 0>(), (3, 1));
+            /// ```
+            fn select2<const I0: usize, const I1: usize>(&self) -> (Self::Item, Self::Item)
             where
-                Self::Item: ::std::ops::MulAssign
+                Self::Item: Clone,
             {
-                let (mut acc, $($name_reduced,)*) = self;
-                $(acc *= $name_reduced;)*
-                acc
+                let r = self.by_ref();
+                (r.nth_const::<I0>().clone(), r.nth_const::<I1>().clone())
             }
 
-            #[allow(unused_mut)]
-            fn tmax(self) -> Self::Item
+            /// Builds `(x_I0, x_I1, x_I2)` by cloning the elements at the given indices.
+            /// The indices are checked at compile time and may repeat.
+            /// # Example
+            /// ```ignore
+            /// let a = (1, 2, 3, ...);
+            /// assert_eq!(a.select3::<2, 1, 0>(), (3, 2, 1));
+            /// ```
+            fn select3<const I0: usize, const I1: usize, const I2: usize>(
+                &self,
+            ) -> (Self::Item, Self::Item, Self::Item)
             where
-                Self::Item: ::std::cmp::PartialOrd
+                Self::Item: Clone,
             {
-                let (mut acc, $($name_reduced,)*) = self;
-                $(if acc < $name_reduced {
-                    acc = $name_reduced;
-                })*
-                acc
+                let r = self.by_ref();
+                (
+                    r.nth_const::<I0>().clone(),
+                    r.nth_const::<I1>().clone(),
+                    r.nth_const::<I2>().clone(),
+                )
             }
 
-            #[allow(unused_mut)]
-            fn tmin(self) -> Self::Item
+            /// Builds `(x_I0, x_I1, x_I2, x_I3)` by cloning the elements at the given indices.
+            /// The indices are checked at compile time and may repeat.
+            /// # Example
+            /// ```ignore
+            /// let a = (1, 2, 3, ...);
+            /// assert_eq!(a.select4::<0, 0, 1, 1>(), (1, 1, 2, 2));
+            /// ```
+            fn select4<const I0: usize, const I1: usize, const I2: usize, const I3: usize>(
+                &self,
+            ) -> (Self::Item, Self::Item, Self::Item, Self::Item)
             where
-                Self::Item: ::std::cmp::PartialOrd
+                Self::Item: Clone,
             {
-                let (mut acc, $($name_reduced,)*) = self;
-                $(if acc > $name_reduced {
-                    acc = $name_reduced;
-                })*
-                acc
+                let r = self.by_ref();
+                (
+                    r.nth_const::<I0>().clone(),
+                    r.nth_const::<I1>().clone(),
+                    r.nth_const::<I2>().clone(),
+                    r.nth_const::<I3>().clone(),
+                )
             }
 
-            fn zip<U, B>(self, other: U) -> ($((Self::$item, $other),)*)
+            /// Takes `(future, future, future, ...)` and returns a future which resolves to
+            /// the output of the first completed future and its index, dropping the rest.
+            /// If several futures are ready at the same time, the leftmost one wins.
+            /// Enabled by `futures` feature.
+            /// # Example
+            /// ```ignore
+            /// let a = (future::pending(), future::ready(4), future::ready(5), ...);
+            /// assert_eq!(block_on(a.select_first()), (4, 1));
+            /// ```
+            #[cfg(feature = "futures")]
+            fn select_first(self) -> $crate::future::SelectFirst<Self::Item>
             where
-                U: $trait<Item = B>
+                Self::Item: ::std::future::Future,
+                Self: Sized,
             {
-                let ($($name,)*) = self;
-                let ($($name2,)*) = other.id();
-                ($(($name, $name2),)*)
+                let futures = self.fold(Vec::new(), |mut v, f| {
+                    v.push(Box::pin(f));
+                    v
+                });
+                $crate::future::SelectFirst::new(futures)
             }
 
-            fn zipf<U, I, F, B>(self, other: U, mut f: F) -> ($($other,)*)
+            /// Sets the `i`th element to `value`.
+            /// Returns `OutOfRange` if `i` is out of bounds.
+            /// # Example
+            /// ```ignore
+            /// let mut a = (3, 4, 5, ...);
+            /// a.set(1, 10).unwrap();
+            /// assert_eq!(a, (3, 10, 5, ...));
+            /// assert!(a.set(100, 0).is_err());
+            /// ```
+            fn set(&mut self, i: usize, value: Self::Item) -> Result<(), $crate::error::OutOfRange> {
+                match self.get_mut(i) {
+                    Some(x) => {
+                        *x = value;
+                        Ok(())
+                    }
+                    None => Err($crate::error::OutOfRange {
+                        index: i,
+                        len: Self::ARITY,
+                    }),
+                }
+            }
+
+            /// Takes `(a, b, c, d, ...)` and returns the tuple without the first `K` elements.
+            /// `K` is checked at compile time, so it must not exceed the length.
+            /// # Example
+            /// ```ignore
+            /// let a = (1, 2, 3, 4, ...);
+            /// assert_eq!(a.skip::<2>(), (3, 4, ...));
+            /// ```
+            fn skip<const K: usize>(self) -> <Self as $crate::arity::SplitAt<K>>::Back
             where
-                U: $trait<Item = I>,
-                F: FnMut(Self::Item, I) -> B
+                Self: $crate::arity::SplitAt<K> + Sized,
             {
-                let ($($name,)*) = self;
-                let ($($name2,)*) = other.id();
-                ($(f($name, $name2),)*)
+                $crate::arity::SplitAt::split_tuple(self).1
             }
-        }
-    };
-}
 
-impl_tuple_map!{
-    TupleMap1,
-    ,
-    a,
+            /// Takes `(a, b, c, ...)` then returns the tuple sorted in ascending order by
+            /// `total_cmp`, so the result is deterministic even if it contains NaN or signed zeros.
+            /// # Example
+            /// ```ignore
+            /// let a = (2.0, f64::NAN, -0.0, 0.0, -1.0, ...);
+            /// let (b, c, d, e, f, ...) = a.sorted_total();
+            /// assert_eq!((b, c, e), (-1.0, -0.0, 2.0));
+            /// assert!(f.is_nan());
+            /// ```
+            fn sorted_total(self) -> ($(Self::$item,)*)
+            where
+                Self::Item: $crate::num::TotalOrd;
+
+            /// Takes a closure `f` and (a, a, a, ...), then runs `f` for each element in its own
+            /// thread using `std::thread::scope`, and returns (f(a), f(a), f(a), ...).
+            /// If any thread panics, the panic is propagated to the caller.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, ...);
+            /// assert_eq!(a.spawn_map(|x| x * 2), (6, 8, 10, ...));
+            /// ```
+            fn spawn_map<B, F>(self, f: F) -> ($($other,)*)
+            where
+                F: Fn(Self::Item) -> B + Sync,
+                Self::Item: Send,
+                B: Send;
+
+            /// Builds `(x, x, x, ...)` by cloning `x` into every element.
+            /// # Example
+            /// ```ignore
+            /// let a: (i32, i32, i32) = TupleMap3::splat(1);
+            /// assert_eq!(a, (1, 1, 1));
+            /// ```
+            fn splat(x: Self::Item) -> Self
+            where
+                Self: Sized,
+                Self::Item: Clone;
+
+            /// Splits elements into groups separated by elements which satisfy `f`, like
+            /// [`slice::split`](https://doc.rust-lang.org/std/primitive.slice.html#method.split).
+            /// Separators are not contained in the result.
+            /// # Example
+            /// ```ignore
+            /// let a = (1, 0, 2, 3, 0, ...);
+            /// assert_eq!(a.split(|&x| x == 0), vec![vec![1], vec![2, 3], ...]);
+            /// ```
+            fn split<F>(self, mut f: F) -> Vec<Vec<Self::Item>>
+            where
+                F: FnMut(&Self::Item) -> bool,
+                Self: Sized,
+            {
+                let mut groups = Vec::new();
+                let mut current = Vec::new();
+                self.for_each(|x| {
+                    if f(&x) {
+                        groups.push(::std::mem::take(&mut current));
+                    } else {
+                        current.push(x);
+                    }
+                });
+                groups.push(current);
+                groups
+            }
+
+            /// Takes `(a, b, c, d, ...)` and splits it into the tuple of the first `K` elements
+            /// and the tuple of the rest.
+            /// `K` is checked at compile time, so it must not exceed the length.
+            /// # Example
+            /// ```ignore
+            /// let a = (1, 2, 3, 4, ...);
+            /// assert_eq!(a.split_at::<2>(), ((1, 2), (3, 4, ...)));
+            /// ```
+            fn split_at<const K: usize>(
+                self,
+            ) -> (<Self as $crate::arity::SplitAt<K>>::Front, <Self as $crate::arity::SplitAt<K>>::Back)
+            where
+                Self: $crate::arity::SplitAt<K> + Sized,
+            {
+                $crate::arity::SplitAt::split_tuple(self)
+            }
+
+            /// Checks if the leading elements of the tuple are equal to `prefix`, which is
+            /// a shorter(or the same length) tuple, a slice, or an array.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, ...);
+            /// assert!(a.starts_with(&(3, 4)));
+            /// assert!(a.starts_with(&[3]));
+            /// assert!(!a.starts_with(&[4]));
+            /// ```
+            fn starts_with<P>(&self, prefix: &P) -> bool
+            where
+                P: $crate::arity::Affix<Self> + ?Sized,
+                Self: Sized,
+            {
+                prefix.is_prefix_of(self)
+            }
+
+            /// Swaps the `i`-th and `j`-th elements of `&mut (a, b, c, ...)` in place.
+            /// # Panics
+            /// Panics if `i` or `j` is out of bounds.
+            /// # Example
+            /// ```ignore
+            /// let mut a = (1, 2, 3);
+            /// a.swap(0, 2);
+            /// assert_eq!(a, (3, 2, 1));
+            /// ```
+            fn swap(&mut self, i: usize, j: usize);
+
+            /// Swaps each element of `&mut (a, b, c, ...)` with the corresponding element of `other`.
+            /// # Example
+            /// ```ignore
+            /// let mut a = (1, 2, 3);
+            /// let mut b = (4, 5, 6);
+            /// a.swap_with(&mut b);
+            /// assert_eq!(a, (4, 5, 6));
+            /// assert_eq!(b, (1, 2, 3));
+            /// ```
+            fn swap_with(&mut self, other: &mut Self);
+
+            /// Takes `(a, b, c, d, ...)` and returns the tuple of the first `K` elements.
+            /// `K` is checked at compile time, so it must not exceed the length.
+            /// # Example
+            /// ```ignore
+            /// let a = (1, 2, 3, 4, ...);
+            /// assert_eq!(a.take::<2>(), (1, 2));
+            /// ```
+            fn take<const K: usize>(self) -> <Self as $crate::arity::SplitAt<K>>::Front
+            where
+                Self: $crate::arity::SplitAt<K> + Sized,
+            {
+                $crate::arity::SplitAt::split_tuple(self).0
+            }
+
+            /// Takes `(a, b, c, ...)` then returns the maximum value of tuple.
+            /// This method is named `tmax` instead of `max`, to avoid overlap
+            /// to `std::cmp::ord::max`.
+            fn tmax(self) -> Self::Item
+            where
+                Self::Item: ::std::cmp::PartialOrd;
+
+            /// Takes `(a, b, c, ...)` then returns the minimum value of tuple.
+            /// This method is named `tmin` instead of `min`, to avoid overlap
+            /// to `std::cmp::ord::min`.
+            fn tmin(self) -> Self::Item
+            where
+                Self::Item: ::std::cmp::PartialOrd;
+
+            /// Same as `fold`, but `f` returns `Result` and the folding stops at the first `Err`,
+            /// like [`std::iter::Iterator::try_fold`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.try_fold).
+            /// # Example
+            /// ```ignore
+            /// let a = (3u8, 200, 100, ...);
+            /// assert_eq!(a.try_fold(0u8, |sum, x| sum.checked_add(x).ok_or(x)), Err(100));
+            /// ```
+            fn try_fold<B, E, F>(self, init: B, f: F) -> Result<B, E>
+            where
+                F: FnMut(B, Self::Item) -> Result<B, E>;
+
+            /// Same as `for_each`, but `f` returns `Result` and stops at the first `Err`,
+            /// like [`std::iter::Iterator::try_for_each`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.try_for_each).
+            /// # Example
+            /// ```ignore
+            /// let a = ("1", "x", "3", ...);
+            /// let mut v = vec![];
+            /// assert!(a.try_for_each(|s| s.parse::<i32>().map(|x| v.push(x))).is_err());
+            /// assert_eq!(v, vec![1]);
+            /// ```
+            fn try_for_each<E, F>(self, f: F) -> Result<(), E>
+            where
+                F: FnMut(Self::Item) -> Result<(), E>;
+
+            /// Builds a tuple from exactly as many items of `iter` as the length of the tuple.
+            /// Returns `None` if `iter` yields fewer or more items.
+            /// # Example
+            /// ```ignore
+            /// let a: Option<(i32, i32, i32)> = TupleMap3::try_from_iter(vec![1, 2, 3]);
+            /// assert_eq!(a, Some((1, 2, 3)));
+            /// let b: Option<(i32, i32, i32)> = TupleMap3::try_from_iter(vec![1, 2]);
+            /// assert_eq!(b, None);
+            /// ```
+            fn try_from_iter<I>(iter: I) -> Option<Self>
+            where
+                Self: Sized,
+                I: IntoIterator<Item = Self::Item>;
+
+            /// Builds a tuple by cloning the elements of `s`.
+            /// Returns `LenError` if the length of `s` differs from the length of the tuple.
+            /// # Example
+            /// ```ignore
+            /// let a: Result<(i32, i32, i32), _> = TupleMap3::try_from_slice(&[1, 2, 3]);
+            /// assert_eq!(a, Ok((1, 2, 3)));
+            /// let b: Result<(i32, i32, i32), _> = TupleMap3::try_from_slice(&[1, 2, 3, 4]);
+            /// assert_eq!(b, Err(LenError { expected: 3, actual: 4 }));
+            /// ```
+            fn try_from_slice(s: &[Self::Item]) -> Result<Self, $crate::error::LenError>
+            where
+                Self: Sized,
+                Self::Item: Clone;
+
+            /// Builds a tuple from the elements of `v`.
+            /// Returns `LenError` if the length of `v` differs from the length of the tuple.
+            /// # Example
+            /// ```ignore
+            /// let a: Result<(i32, i32, i32), _> = TupleMap3::try_from_vec(vec![1, 2, 3]);
+            /// assert_eq!(a, Ok((1, 2, 3)));
+            /// let b: Result<(i32, i32, i32), _> = TupleMap3::try_from_vec(vec![1, 2]);
+            /// assert_eq!(b, Err(LenError { expected: 3, actual: 2 }));
+            /// ```
+            fn try_from_vec(v: Vec<Self::Item>) -> Result<Self, $crate::error::LenError>
+            where
+                Self: Sized;
+
+            /// Takes `(a, a, a, ...)` and `(b, b, b, ...)` and closure f which returns `Result`,
+            /// then returns `Ok((c, c, c, ...))` if all `f(a, b)` returns `Ok(c)`,
+            /// otherwise returns the first error.
+            /// # Example
+            /// ```ignore
+            /// let a = (6, 8, 10, ...);
+            /// let b = (3, 4, 5, ...);
+            /// assert_eq!(a.try_zipf(b, |x, y| x.checked_div(y).ok_or(())), Ok((2, 2, 2, ...)));
+            /// ```
+            fn try_zipf<U, I, F, B, E>(self, other: U, f: F) -> Result<($($other,)*), E>
+            where
+                U: $trait<Item = I>,
+                F: FnMut(Self::Item, I) -> Result<B, E>;
+
+            /// Takes `(a, b, c, ...)` and returns `(a, (b, c, ...))`.
+            /// # Example
+            /// ```ignore
+            /// let a = (1, 2, 3, ...);
+            /// assert_eq!(a.uncons(), (1, (2, 3, ...)));
+            /// ```
+            fn uncons(self) -> (Self::Item, ($(replace_ty!($name_reduced Self::Item),)*));
+
+            /// Converts `f` taking separate arguments into a function taking them as a tuple.
+            /// # Example
+            /// ```ignore
+            /// let f = <(i32, i32, i32)>::uncurry(|x, y, z| x * y + z);
+            /// assert_eq!(f((3, 4, 5)), 17);
+            /// ```
+            fn uncurry<R, F>(f: F) -> impl Fn(Self) -> R
+            where
+                F: Fn($(Self::$item,)*) -> R,
+                Self: Sized,
+            {
+                move |t| t.call(&f)
+            }
+
+            /// Takes `(..., x, y, z)` and returns `((..., x, y), z)`.
+            /// # Example
+            /// ```ignore
+            /// let a = (..., 1, 2, 3);
+            /// assert_eq!(a.unsnoc(), ((..., 1, 2), 3));
+            /// ```
+            fn unsnoc(self) -> (($(replace_ty!($name_reduced Self::Item),)*), Self::Item);
+
+            /// Takes `((a, b), (a, b), (a, b), ...)` and returns `((a, a, a, ...), (b, b, b, ...))`.
+            /// This is the inverse of `zip`.
+            /// # Example
+            /// ```ignore
+            /// let a = ((1, "a"), (2, "b"), (3, "c"), ...);
+            /// assert_eq!(a.unzip(), ((1, 2, 3, ...), ("a", "b", "c", ...)));
+            /// ```
+            fn unzip<A, B>(self) -> (($(replace_ty!($idx A),)*), ($($other,)*))
+            where
+                Self: $trait<Item = (A, B)> + Sized,
+            {
+                let ($($name,)*) = self.id();
+                (($($name.0,)*), ($($name.1,)*))
+            }
+
+            /// Takes `(a, b, ...)` and returns the tuple of length `M`, padded with
+            /// `Default::default()`.
+            /// `M` is checked at compile time, so it must not be less than the length.
+            /// # Example
+            /// ```ignore
+            /// let a = (1.0, 2.0);
+            /// assert_eq!(a.widen::<3>(), (1.0, 2.0, 0.0));
+            /// ```
+            fn widen<const M: usize>(self) -> <Self as $crate::arity::Widen<M>>::Output
+            where
+                Self: $crate::arity::Widen<M> + Sized,
+            {
+                $crate::arity::Widen::widen_tuple(self)
+            }
+
+            /// Takes `(a, a, a, ...)` and `(b, b, b, ...)` then returns `((a, b), (a, b), (a, b), ...)` 
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, ...);
+            /// let b = ('a', 'b', 'c', ...);
+            /// assert_eq!(a.zip(b), ((3, 'a'), (4, 'b'), (5, 'c'), ...));
+            /// ```
+            fn zip<U, B>(self, other: U) -> ($((Self::$item, $other),)*)
+            where
+                U: $trait<Item = B>;
+
+            /// Takes `(a, a, a, ...)`, `(b, b, b, ...)` and `(c, c, c, ...)`,
+            /// then returns `((a, b, c), (a, b, c), (a, b, c), ...)`.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, ...);
+            /// let b = ('a', 'b', 'c', ...);
+            /// let c = (true, false, true, ...);
+            /// assert_eq!(a.zip3(b, c), ((3, 'a', true), (4, 'b', false), (5, 'c', true), ...));
+            /// ```
+            fn zip3<U, V, I, J>(self, b: U, c: V) -> ($((Self::$item, I, J),)*)
+            where
+                U: $trait<Item = I>,
+                V: $trait<Item = J>,
+                Self: Sized,
+            {
+                self.zipf3(b, c, |x, y, z| (x, y, z))
+            }
+
+            /// Takes `(a, a, a, ...)` and a shorter(or the same length) tuple `(b, b, ...)`,
+            /// then returns `((a, b), (a, b), (a, fill), ...)`, padding `other` with `fill`.
+            /// To pad with `None`, map `other` by `Some` before.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, ...);
+            /// let b = ('a', 'b');
+            /// assert_eq!(a.zip_pad(b, 'z'), ((3, 'a'), (4, 'b'), (5, 'z'), ...));
+            /// ```
+            fn zip_pad<U, B>(self, other: U, fill: B) -> ($((Self::$item, $other),)*)
+            where
+                U: $crate::arity::Pad<($($other,)*), Item = B>,
+                Self: Sized,
+            {
+                self.zip(other.pad(fill))
+            }
+
+            /// Takes `(a, a, a, ...)` and `(b, b, b, ...)` and closure f,
+            /// then returns `(f(a, b), f(a, b), f(a, b), ...)` 
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, ...);
+            /// let b = ('a', 'b', 'c', ...);
+            /// assert_eq!(
+            ///     a.zipf(b, |x, y| format!("{}{}", x, y)),
+            ///     ("3a", "4b", "5c", ...).map(|x| x.to_owned())
+            /// );
+            /// ```
+            fn zipf<U, I, F, B>(self, other: U, f: F) -> ($($other,)*)
+            where
+                U: $trait<Item = I>,
+                F: FnMut(Self::Item, I) -> B;
+
+            /// Takes `(a, a, a, ...)`, `(b, b, b, ...)`, `(c, c, c, ...)` and closure f,
+            /// then returns `(f(a, b, c), f(a, b, c), f(a, b, c), ...)`.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, ...);
+            /// let b = (1, 2, 3, ...);
+            /// let c = (2, 2, 2, ...);
+            /// assert_eq!(a.zipf3(b, c, |x, y, z| (x + y) * z), (8, 12, 16, ...));
+            /// ```
+            fn zipf3<U, V, I, J, F, B>(self, b: U, c: V, mut f: F) -> ($($other,)*)
+            where
+                U: $trait<Item = I>,
+                V: $trait<Item = J>,
+                F: FnMut(Self::Item, I, J) -> B,
+                Self: Sized,
+            {
+                self.zip(b).zipf(c, |(x, y), z| f(x, y, z))
+            }
+        }
+
+        /// Arithmetic methods for tuples: element-wise operations and aggregations.
+        pub trait $arith: $trait {
+            /// Takes `(a, b, c, ...)` then returns `a + b + c ...`
+            fn sum(self) -> Self::Item
+            where
+                 Self::Item: ::std::ops::AddAssign;
+
+            /// Takes `(a, b, c, ...)` then returns `a + b + c ...`
+            fn product(self) -> Self::Item
+            where
+                 Self::Item: ::std::ops::MulAssign;
+
+            /// Takes `(a, b, c, ...)` then returns the minimum, maximum, sum, mean and count
+            /// of elements as [`TupleSummary`](struct.TupleSummary.html), in one pass.
+            /// # Example
+            /// ```ignore
+            /// let s = (6, 8, 10).summary();
+            /// assert_eq!((s.min, s.max, s.sum, s.mean, s.count), (6, 10, 24, 8.0, 3));
+            /// ```
+            fn summary(self) -> TupleSummary<Self::Item>
+            where
+                Self::Item: Clone + ::std::cmp::PartialOrd + ::std::ops::AddAssign + $crate::num::ToF64;
+
+            /// Takes `(a, a, a, ...)` and `(b, b, b, ...)`,
+            /// then returns `(a + b, a + b, a + b, ...)` 
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, ...);
+            /// let b = (7, 8, 9, ....)
+            /// assert_eq!(a.add(b), (10, 12, 14, ...));
+            /// ```
+            fn add<U, I, B>(self, other: U) -> ($($other,)*)
+            where
+                U: $trait<Item = I>,
+                Self::Item: ::std::ops::Add<I, Output = B>,
+                Self: Sized,
+            {
+                self.elem_add(other)
+            }
+
+            /// Same as [`add`](#method.add), but named so as not to conflict with
+            /// `std::ops::Add::add` in generic code.
+            /// # Example
+            /// ```ignore
+            /// let a = (6, 8, 10, ...);
+            /// let b = (3, 4, 5, ...);
+            /// assert_eq!(a.elem_add(b), a.add(b));
+            /// ```
+            fn elem_add<U, I, B>(self, other: U) -> ($($other,)*)
+            where
+                U: $trait<Item = I>,
+                Self::Item: ::std::ops::Add<I, Output = B>,
+                Self: Sized,
+            {
+                self.zipf(other, |a, b| a + b)
+            }
+
+            /// Takes `&mut (a, a, a, ...)` and `(b, b, b, ...)` then performs `a += b` in place.
+            /// Unlike [`add`](#method.add), elements are not moved, which avoids needless
+            /// clones for non-`Copy` numeric types.
+            /// # Example
+            /// ```ignore
+            /// let mut a = (6, 8, 10, ...);
+            /// a.add_assign((3, 4, 5, ...));
+            /// assert_eq!(a, (9, 12, 15, ...));
+            /// ```
+            fn add_assign<U, I>(&mut self, other: U)
+            where
+                U: $trait<Item = I>,
+                Self::Item: ::std::ops::AddAssign<I>,
+            {
+                self.by_ref_mut().zipf(other, |a, b| *a += b);
+            }
+
+            /// Takes `(a, a, a, ...)` and `(b, b, b, ...)`,
+            /// then returns `(a - b, a - b, a - b, ...)` 
+            /// # Example
+            /// ```ignore
+            /// let a = (7, 8, 9, ....);
+            /// let b = (3, 4, 5, ....);
+            /// assert!(a.sub(b).same());
+            /// ```
+            fn sub<U, I, B>(self, other: U) -> ($($other,)*)
+            where
+                U: $trait<Item = I>,
+                Self::Item: ::std::ops::Sub<I, Output = B>,
+                Self: Sized,
+            {
+                self.elem_sub(other)
+            }
+
+            /// Same as [`sub`](#method.sub), but named so as not to conflict with
+            /// `std::ops::Sub::sub` in generic code.
+            /// # Example
+            /// ```ignore
+            /// let a = (6, 8, 10, ...);
+            /// let b = (3, 4, 5, ...);
+            /// assert_eq!(a.elem_sub(b), a.sub(b));
+            /// ```
+            fn elem_sub<U, I, B>(self, other: U) -> ($($other,)*)
+            where
+                U: $trait<Item = I>,
+                Self::Item: ::std::ops::Sub<I, Output = B>,
+                Self: Sized,
+            {
+                self.zipf(other, |a, b| a - b)
+            }
+
+            /// Takes `&mut (a, a, a, ...)` and `(b, b, b, ...)` then performs `a -= b` in place.
+            /// # Example
+            /// ```ignore
+            /// let mut a = (6, 8, 10, ...);
+            /// a.sub_assign((3, 4, 5, ...));
+            /// assert_eq!(a, (3, 4, 5, ...));
+            /// ```
+            fn sub_assign<U, I>(&mut self, other: U)
+            where
+                U: $trait<Item = I>,
+                Self::Item: ::std::ops::SubAssign<I>,
+            {
+                self.by_ref_mut().zipf(other, |a, b| *a -= b);
+            }
+
+            /// Takes `(a, a, a, ...)` and `(b, b, b, ...)`,
+            /// then returns `(a * b, a * b, a * b, ...)` 
+            /// # Example
+            /// ```ignore
+            /// let a = (7, 8, 9, ....);
+            /// let b = (3, 4, 5, ....);
+            /// assert_eq!(a.mul(b), (21, 32, 45, ...));
+            /// ```
+            fn mul<U, I, B>(self, other: U) -> ($($other,)*)
+            where
+                U: $trait<Item = I>,
+                Self::Item: ::std::ops::Mul<I, Output = B>,
+                Self: Sized,
+            {
+                self.elem_mul(other)
+            }
+
+            /// Same as [`mul`](#method.mul), but named so as not to conflict with
+            /// `std::ops::Mul::mul` in generic code.
+            /// # Example
+            /// ```ignore
+            /// let a = (6, 8, 10, ...);
+            /// let b = (3, 4, 5, ...);
+            /// assert_eq!(a.elem_mul(b), a.mul(b));
+            /// ```
+            fn elem_mul<U, I, B>(self, other: U) -> ($($other,)*)
+            where
+                U: $trait<Item = I>,
+                Self::Item: ::std::ops::Mul<I, Output = B>,
+                Self: Sized,
+            {
+                self.zipf(other, |a, b| a * b)
+            }
+
+            /// Takes `&mut (a, a, a, ...)` and `(b, b, b, ...)` then performs `a *= b` in place.
+            /// # Example
+            /// ```ignore
+            /// let mut a = (6, 8, 10, ...);
+            /// a.mul_assign((3, 4, 5, ...));
+            /// assert_eq!(a, (18, 32, 50, ...));
+            /// ```
+            fn mul_assign<U, I>(&mut self, other: U)
+            where
+                U: $trait<Item = I>,
+                Self::Item: ::std::ops::MulAssign<I>,
+            {
+                self.by_ref_mut().zipf(other, |a, b| *a *= b);
+            }
+
+            /// Takes `(a, a, a, ...)` and `(b, b, b, ...)`,
+            /// then returns `(a * b, a * b, a * b, ...)` 
+            /// # Example
+            /// ```ignore
+            /// let a = (6, 8, 10, ....);
+            /// let b = (3, 4, 5, ....);
+            /// assert!(a.div(b).same());
+            /// ```
+            fn div<U, I, B>(self, other: U) -> ($($other,)*)
+            where
+                U: $trait<Item = I>,
+                Self::Item: ::std::ops::Div<I, Output = B>,
+                Self: Sized,
+            {
+                self.elem_div(other)
+            }
+
+            /// Same as [`div`](#method.div), but named so as not to conflict with
+            /// `std::ops::Div::div` in generic code.
+            /// # Example
+            /// ```ignore
+            /// let a = (6, 8, 10, ...);
+            /// let b = (3, 4, 5, ...);
+            /// assert_eq!(a.elem_div(b), a.div(b));
+            /// ```
+            fn elem_div<U, I, B>(self, other: U) -> ($($other,)*)
+            where
+                U: $trait<Item = I>,
+                Self::Item: ::std::ops::Div<I, Output = B>,
+                Self: Sized,
+            {
+                self.zipf(other, |a, b| a / b)
+            }
+
+            /// Takes `&mut (a, a, a, ...)` and `(b, b, b, ...)` then performs `a /= b` in place.
+            /// # Example
+            /// ```ignore
+            /// let mut a = (6, 8, 10, ...);
+            /// a.div_assign((3, 4, 5, ...));
+            /// assert_eq!(a, (2, 2, 2, ...));
+            /// ```
+            fn div_assign<U, I>(&mut self, other: U)
+            where
+                U: $trait<Item = I>,
+                Self::Item: ::std::ops::DivAssign<I>,
+            {
+                self.by_ref_mut().zipf(other, |a, b| *a /= b);
+            }
+
+            /// Takes `(a, a, a, ...)` and `(b, b, b, ...)`,
+            /// then returns the midpoint of each pair without overflow.
+            /// Integers use the `midpoint` algorithm of std, and floats use plain average.
+            /// # Example
+            /// ```ignore
+            /// let a = (0u8, 10, 255, ...);
+            /// let b = (255u8, 20, 255, ...);
+            /// assert_eq!(a.midpoint(b), (127, 15, 255, ...));
+            /// ```
+            fn midpoint<U>(self, other: U) -> ($(Self::$item,)*)
+            where
+                U: $trait<Item = Self::Item>,
+                Self::Item: $crate::num::Midpoint,
+                Self: Sized,
+            {
+                self.zipf(other, $crate::num::Midpoint::midpoint)
+            }
+
+            /// Takes `(a, a, a, ...)` and `(b, b, b, ...)`,
+            /// then returns `(|a - b|, |a - b|, |a - b|, ...)` without overflow.
+            /// For signed integers, the result is the unsigned type of the same width.
+            /// # Example
+            /// ```ignore
+            /// let a = (3u8, 200, 7, ...);
+            /// let b = (5u8, 100, 7, ...);
+            /// assert_eq!(a.abs_diff(b), (2, 100, 0, ...));
+            /// ```
+            fn abs_diff<U>(self, other: U) -> ($(<Self::$item as $crate::num::AbsDiff>::Output,)*)
+            where
+                U: $trait<Item = Self::Item>,
+                Self::Item: $crate::num::AbsDiff,
+                Self: Sized,
+            {
+                self.zipf(other, $crate::num::AbsDiff::abs_diff)
+            }
+
+            /// Builds a tuple of values evenly spaced from `start` to `end`, both inclusive.
+            /// # Example
+            /// ```ignore
+            /// let a: (f64, f64, f64) = TupleArith3::linspace(0.0, 1.0);
+            /// assert_eq!(a, (0.0, 0.5, 1.0));
+            /// ```
+            fn linspace(start: Self::Item, end: Self::Item) -> Self
+            where
+                Self: Sized,
+                Self::Item: Copy + $crate::num::Linspace;
+        }
+
+        /// Conversion methods for tuples: collecting into other containers and
+        /// transforming the kind of each element.
+        pub trait $convert: $trait {
+            /// Takes `(Option<a>, Option<a>, ...)` and calls `f` with each inner value,
+            /// like `Option::and_then`. `None` elements stay `None`.
+            /// # Example
+            /// ```ignore
+            /// let a = (Some(4), None, Some(-1), ...);
+            /// let b = a.and_then_each(|x| if x > 0 { Some(x * 2) } else { None });
+            /// assert_eq!(b, (Some(8), None, None, ...));
+            /// ```
+            fn and_then_each<A, B, F>(self, mut f: F) -> ($(Option<$other>,)*)
+            where
+                Self: $trait<Item = Option<A>> + Sized,
+                F: FnMut(A) -> Option<B>,
+            {
+                self.map(|x| x.and_then(&mut f))
+            }
+
+            /// Takes `&(&mut a, &mut a, &mut a, ...)` and returns `(&a, &a, &a, ...)`
+            /// borrowed from `self`.
+            /// # Example
+            /// ```ignore
+            /// let mut a = (3, 4, 5, ...);
+            /// let b = a.by_ref_mut();
+            /// assert_eq!(b.as_shared(), (&3, &4, &5, ...));
+            /// ```
+            fn as_shared<'s, 'a: 's, B>(&'s self) -> ($(&'s $other,)*)
+            where
+                Self: $trait<Item = &'a mut B>,
+                B: ?Sized + 'a,
+            {
+                self.by_ref().map(|x| &**x)
+            }
+
+            /// Collects `(a, b, c, ...)` into any collection implementing `FromIterator`,
+            /// like [`std::iter::Iterator::collect`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.collect).
+            /// # Example
+            /// ```ignore
+            /// use std::collections::BTreeSet;
+            /// let a = (3, 1, 3, ...);
+            /// let b: BTreeSet<_> = a.collect();
+            /// assert!(b.contains(&1));
+            /// ```
+            fn collect<C>(self) -> C
+            where
+                C: ::std::iter::FromIterator<Self::Item>,
+                Self: Sized,
+            {
+                C::from_iter(self.into_array())
+            }
+
+            /// Takes `(char, char, char, ...)` and collects them into `String`.
+            /// # Example
+            /// ```ignore
+            /// let a = ('a', 'b', 'c', ...);
+            /// assert_eq!(a.collect_string(), "abc...");
+            /// ```
+            fn collect_string(self) -> String
+            where
+                Self: $trait<Item = char> + Sized,
+            {
+                self.fold(String::new(), |mut s, c| {
+                    s.push(c);
+                    s
+                })
+            }
+
+            /// Takes `(a, a, a, ...)` whose elements are `&str` or `String`,
+            /// then returns the concatenation of them.
+            /// # Example
+            /// ```ignore
+            /// let a = ("a", "b", "c", ...);
+            /// assert_eq!(a.concat_str(), "abc...");
+            /// ```
+            fn concat_str(self) -> String
+            where
+                Self::Item: AsRef<str>,
+                Self: Sized,
+            {
+                self.fold(String::new(), |mut s, x| {
+                    s.push_str(x.as_ref());
+                    s
+                })
+            }
+
+            /// Takes `(&mut a, &mut a, &mut a, ...)` and returns `(&a, &a, &a, ...)`
+            /// with the same lifetime.
+            /// # Example
+            /// ```ignore
+            /// let mut a = (3, 4, 5, ...);
+            /// let b = a.by_ref_mut();
+            /// assert_eq!(b.downgrade(), (&3, &4, &5, ...));
+            /// ```
+            fn downgrade<'a, B>(self) -> ($(&'a $other,)*)
+            where
+                Self: $trait<Item = &'a mut B> + Sized,
+                B: ?Sized + 'a,
+            {
+                self.map(|x| &*x)
+            }
+
+            /// Takes `&mut (a, b, c, ...)` and returns `[&mut a, &mut b, &mut c, ...]`,
+            /// like `<[T; N]>::each_mut`.
+            ///
+            /// The memory layout of tuples is not guaranteed, so `&mut [T; N]` can't be borrowed
+            /// from a tuple soundly. The array of references is the safe alternative.
+            /// # Example
+            /// ```ignore
+            /// let mut a = (3, 1, 2, ...);
+            /// for x in a.each_mut_array() {
+            ///     *x *= 2;
+            /// }
+            /// assert_eq!(a, (6, 2, 4, ...));
+            /// ```
+            fn each_mut_array(&mut self) -> [&mut Self::Item; 0 $(+ replace_expr!($idx 1))*] {
+                self.by_ref_mut().into_array()
+            }
+
+            /// Takes `&(a, b, c, ...)` and returns `[&a, &b, &c, ...]`,
+            /// like `<[T; N]>::each_ref`.
+            ///
+            /// The memory layout of tuples is not guaranteed, so `&[T; N]` can't be borrowed
+            /// from a tuple soundly. The array of references is the safe alternative.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 1, 2, ...);
+            /// assert_eq!(a.each_ref_array().iter().map(|x| **x).max(), Some(3));
+            /// ```
+            fn each_ref_array(&self) -> [&Self::Item; 0 $(+ replace_expr!($idx 1))*] {
+                self.by_ref().into_array()
+            }
+
+            /// Takes `(Option<a>, Option<a>, ...)` or `(Result<a, e>, Result<a, e>, ...)`
+            /// and unwraps all elements, like `unwrap_all` but panics with `msg`.
+            /// # Panics
+            /// Panics if any element is `None` or `Err`, with the message containing
+            /// `msg` and the index of the element.
+            /// # Example
+            /// ```ignore
+            /// let a = (Some(3), Some(4), Some(5), ...);
+            /// assert_eq!(a.expect_all("invalid input"), (3, 4, 5, ...));
+            /// ```
+            fn expect_all(self, msg: &str) -> ($(<Self::$item as $crate::unwrap::Unwrap>::Output,)*)
+            where
+                Self::Item: $crate::unwrap::Unwrap,
+                Self: Sized,
+            {
+                let mut i = 0;
+                self.map(|x| {
+                    let x = $crate::unwrap::Unwrap::unwrap_at(x, i, Some(msg));
+                    i += 1;
+                    x
+                })
+            }
+
+            /// Takes `(a, a, a, ...)` and formats each element with `f`, returning
+            /// `(String, String, String, ...)`.
+            /// Unlike `join`, the position of each element is kept.
+            /// # Example
+            /// ```ignore
+            /// let a = (1.5, 2.25, 3.0, ...);
+            /// let b = a.format_each(|x| format!("{:>6.2}", x));
+            /// assert_eq!(b, ("  1.50".to_owned(), "  2.25".to_owned(), "  3.00".to_owned(), ...));
+            /// ```
+            fn format_each<F>(self, f: F) -> ($(replace_ty!($idx String),)*)
+            where
+                F: FnMut(Self::Item) -> String,
+                Self: Sized,
+            {
+                self.map(f)
+            }
+
+            /// Converts `(a, b, c, ...)` into `[a, b, c, ...]`.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 1, 2, ...);
+            /// let mut b = a.into_array();
+            /// b.sort();
+            /// assert_eq!(b, [1, 2, 3, ...]);
+            /// ```
+            fn into_array(self) -> [Self::Item; 0 $(+ replace_expr!($idx 1))*];
+
+            /// Convert tuple into `Box<[T]>`.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, ...);
+            /// assert_eq!(&*a.into_boxed_slice(), &[3, 4, 5, ...]);
+            /// ```
+            fn into_boxed_slice(self) -> Box<[Self::Item]>
+            where
+                Self: Sized,
+            {
+                self.into_vec().into_boxed_slice()
+            }
+
+            /// Takes a closure `f` which converts an element into `Box<D>`, then returns
+            /// `Vec` of them. This is useful to erase elements into trait objects.
+            /// # Example
+            /// ```ignore
+            /// use std::fmt::Display;
+            /// let a = (3, 4, 5, ...);
+            /// let v = a.into_dyn_vec(|x| Box::new(x) as Box<dyn Display>);
+            /// assert_eq!(v[0].to_string(), "3");
+            /// ```
+            fn into_dyn_vec<D, F>(self, f: F) -> Vec<Box<D>>
+            where
+                D: ?Sized,
+                F: FnMut(Self::Item) -> Box<D>,
+                Self: Sized,
+            {
+                self.map(f).into_vec()
+            }
+
+            /// Converts `(a, b, c, ...)` into an iterator yielding `a`, `b`, `c`, ... in order.
+            ///
+            /// `IntoIterator` can't be implemented for tuples outside of std, so use this method
+            /// to iterate over the elements in a `for` loop or with iterator adapters.
+            /// # Example
+            /// ```ignore
+            /// let mut sum = 0;
+            /// for x in (3, 4, 5, ...).into_tuple_iter() {
+            ///     sum += x;
+            /// }
+            /// ```
+            fn into_tuple_iter(
+                self,
+            ) -> $crate::iter::TupleIter<Self::Item, { 0 $(+ replace_expr!($idx 1))* }>
+            where
+                Self: Sized,
+            {
+                $crate::iter::TupleIter::new(self.into_array())
+            }
+
+            /// Convert tuple into Vec.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, ...)
+            /// assert_eq(a.into_vec(), vec![3, 4, 5, ...])
+            /// ```
+            fn into_vec(self) -> Vec<Self::Item>;
+
+            /// Returns an iterator over references to the elements of `(a, b, c, ...)`.
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, ...);
+            /// assert_eq!(a.iter().max(), Some(&5));
+            /// ```
+            fn iter(
+                &self,
+            ) -> $crate::iter::TupleIter<&Self::Item, { 0 $(+ replace_expr!($idx 1))* }> {
+                $crate::iter::TupleIter::new(self.each_ref_array())
+            }
+
+            /// Returns an iterator over mutable references to the elements of `(a, b, c, ...)`.
+            /// # Example
+            /// ```ignore
+            /// let mut a = (3, 4, 5, ...);
+            /// for x in a.iter_mut() {
+            ///     *x += 1;
+            /// }
+            /// assert_eq!(a, (4, 5, 6, ...));
+            /// ```
+            fn iter_mut(
+                &mut self,
+            ) -> $crate::iter::TupleIter<&mut Self::Item, { 0 $(+ replace_expr!($idx 1))* }> {
+                $crate::iter::TupleIter::new(self.each_mut_array())
+            }
+
+            /// Takes `(a, a, a, ...)` whose elements are `&str` or `String`,
+            /// then returns them joined with `sep`.
+            /// # Example
+            /// ```ignore
+            /// let a = ("a", "b", "c", ...);
+            /// assert_eq!(a.join(", "), "a, b, c, ...");
+            /// ```
+            fn join(self, sep: &str) -> String
+            where
+                Self::Item: AsRef<str>;
+
+            /// Takes `&(Mutex<a>, Mutex<a>, ...)` or `&(RwLock<a>, RwLock<a>, ...)`, then
+            /// acquires all locks from left to right and returns the tuple of guards.
+            /// Acquiring locks always in the same order helps to avoid deadlocks.
+            /// # Panics
+            /// Panics if any lock is poisoned.
+            /// # Example
+            /// ```ignore
+            /// let a = (Mutex::new(3), Mutex::new(4), ...);
+            /// let (mut x, mut y, ...) = a.lock_all();
+            /// *x += *y;
+            /// ```
+            fn lock_all<'a>(&'a self) -> ($(<Self::$item as $crate::sync::Lock<'a>>::Guard,)*)
+            where
+                Self::Item: $crate::sync::Lock<'a>,
+            {
+                self.by_ref().map($crate::sync::Lock::lock)
+            }
+
+            /// Takes `&(a, a, a, ...)` and returns `(a.as_ref(), a.as_ref(), a.as_ref(), ...)`,
+            /// e.g. converts `&(String, String, ...)` into `(&str, &str, ...)`.
+            /// # Example
+            /// ```ignore
+            /// let a = (String::from("a"), String::from("b"), ...);
+            /// assert_eq!(a.map_as_ref::<str>(), ("a", "b", ...));
+            /// ```
+            fn map_as_ref<B>(&self) -> ($(&$other,)*)
+            where
+                Self::Item: AsRef<B>,
+                B: ?Sized,
+            {
+                self.by_ref().map(|x| x.as_ref())
+            }
+
+            /// Takes `(a, a, a, ...)` and returns `(Box::new(a), Box::new(a), Box::new(a), ...)`
+            /// # Example
+            /// ```ignore
+            /// let a = (3, 4, 5, ...);
+            /// assert_eq!(a.map_box(), (Box::new(3), Box::new(4), Box::new(5), ...));
+            /// ```
+            fn map_box(self) -> ($(Box<Self::$item>,)*)
+            where
+                Self: Sized,
+            {
+                self.map(Box::new)
+            }
+
+            /// Takes `(Result<a, e>, Result<a, e>, ...)` and maps each error with `f`,
+            /// like `Result::map_err`. `Ok` elements are left untouched.
+            /// # Example
+            /// ```ignore
+            /// let a: (Result<i32, i32>, ...) = (Ok(3), Err(4), Ok(5), ...);
+            /// assert_eq!(a.map_err_each(|e| e.to_string()), (Ok(3), Err("4".to_owned()), Ok(5), ...));
+            /// ```
+            fn map_err_each<A, E, B, F>(self, mut f: F) -> ($(Result<A, $other>,)*)
+            where
+                Self: $trait<Item = Result<A, E>> + Sized,
+                F: FnMut(E) -> B,
+            {
+                self.map(|x| x.map_err(&mut f))
+            }
+
+            /// Takes `(Option<a>, Option<a>, ...)` and maps each inner value with `f`,
+            /// like `Option::map`. `None` elements stay `None`.
+            /// # Example
+            /// ```ignore
+            /// let a = (Some(3), None, Some(5), ...);
+            /// assert_eq!(a.map_inner(|x| x + 1), (Some(4), None, Some(6), ...));
+            /// ```
+            fn map_inner<A, B, F>(self, mut f: F) -> ($(Option<$other>,)*)
+            where
+                Self: $trait<Item = Option<A>> + Sized,
+                F: FnMut(A) -> B,
+            {
+                self.map(|x| x.map(&mut f))
+            }
+
+            /// Takes `(Result<a, e>, Result<a, e>, ...)` and maps each `Ok` value with `f`,
+            /// like `Result::map`. `Err` elements are left untouched.
+            /// # Example
+            /// ```ignore
+            /// let a: (Result<i32, &str>, ...) = (Ok(3), Err("bad"), Ok(5), ...);
+            /// assert_eq!(a.map_ok_each(|x| x + 1), (Ok(4), Err("bad"), Ok(6), ...));
+            /// ```
+            fn map_ok_each<A, E, B, F>(self, mut f: F) -> ($(Result<$other, E>,)*)
+            where
+                Self: $trait<Item = Result<A, E>> + Sized,
+                F: FnMut(A) -> B,
+            {
+                self.map(|x| x.map(&mut f))
+            }
+
+            /// Takes `(&a, &a, &a, ...)` and returns `(a.to_owned(), a.to_owned(), a.to_owned(), ...)`,
+            /// e.g. converts `(&str, &str, ...)` into `(String, String, ...)`.
+            /// # Example
+            /// ```ignore
+            /// let a = ("a", "b", "c", ...);
+            /// assert_eq!(a.map_to_owned(), (String::from("a"), String::from("b"), ...));
+            /// ```
+            fn map_to_owned<'a, B>(self) -> ($(<$other as ToOwned>::Owned,)*)
+            where
+                Self: $trait<Item = &'a B> + Sized,
+                B: ToOwned + ?Sized + 'a,
+            {
+                self.map(B::to_owned)
+            }
+
+            /// Takes `(Result<a, e>, Result<a, e>, ...)` and splits them into
+            /// `Vec` of successes and `Vec` of errors, preserving the order.
+            /// # Example
+            /// ```ignore
+            /// let a = (Ok(3), Err("x"), Ok(5), ...);
+            /// assert_eq!(a.partition_result(), (vec![3, 5, ...], vec!["x", ...]));
+            /// ```
+            fn partition_result<B, E>(self) -> (Vec<B>, Vec<E>)
+            where
+                Self: $trait<Item = Result<B, E>> + Sized,
+            {
+                self.fold((Vec::new(), Vec::new()), |(mut oks, mut errs), r| {
+                    match r {
+                        Ok(x) => oks.push(x),
+                        Err(e) => errs.push(e),
+                    }
+                    (oks, errs)
+                })
+            }
+
+            /// Takes `&mut (&mut a, &mut a, &mut a, ...)` and returns
+            /// `(&mut a, &mut a, &mut a, ...)` borrowed from `self`, so that `self`
+            /// can be used again after the returned references are dropped.
+            /// # Example
+            /// ```ignore
+            /// let mut a = (3, 4, 5, ...);
+            /// let mut b = a.by_ref_mut();
+            /// b.reborrow().for_each(|x| *x += 1);
+            /// b.for_each(|x| *x *= 2);
+            /// assert_eq!(a, (8, 10, 12, ...));
+            /// ```
+            fn reborrow<'s, 'a: 's, B>(&'s mut self) -> ($(&'s mut $other,)*)
+            where
+                Self: $trait<Item = &'a mut B>,
+                B: ?Sized + 'a,
+            {
+                self.by_ref_mut().map(|x| &mut **x)
+            }
+
+            /// Takes `&(Mutex<a>, Mutex<a>, ...)` or `&(RwLock<a>, RwLock<a>, ...)`, then
+            /// tries to acquire all locks from left to right, like `lock_all`.
+            /// If any lock would block or is poisoned, releases acquired locks and returns `None`.
+            /// # Example
+            /// ```ignore
+            /// let a = (Mutex::new(3), Mutex::new(4), ...);
+            /// let guard = a.0.lock().unwrap();
+            /// assert!(a.try_lock_all().is_none());
+            /// ```
+            fn try_lock_all<'a>(&'a self) -> Option<($(<Self::$item as $crate::sync::Lock<'a>>::Guard,)*)>
+            where
+                Self::Item: $crate::sync::Lock<'a>;
+
+            /// Takes `(Option<a>, Option<a>, ...)` or `(Result<a, e>, Result<a, e>, ...)`
+            /// and unwraps all elements.
+            /// # Panics
+            /// Panics if any element is `None` or `Err`, with the message containing
+            /// the index of the element.
+            /// # Example
+            /// ```ignore
+            /// let a: (Result<i32, ()>, ...) = (Ok(3), Ok(4), Ok(5), ...);
+            /// assert_eq!(a.unwrap_all(), (3, 4, 5, ...));
+            /// ```
+            fn unwrap_all(self) -> ($(<Self::$item as $crate::unwrap::Unwrap>::Output,)*)
+            where
+                Self::Item: $crate::unwrap::Unwrap,
+                Self: Sized,
+            {
+                let mut i = 0;
+                self.map(|x| {
+                    let x = $crate::unwrap::Unwrap::unwrap_at(x, i, None);
+                    i += 1;
+                    x
+                })
+            }
+
+            /// Takes `(Option<a>, Option<a>, ...)` and unwraps each element,
+            /// substituting a clone of `default` for `None` elements.
+            /// # Example
+            /// ```ignore
+            /// let a = (Some(3), None, Some(5), ...);
+            /// assert_eq!(a.unwrap_or_all(0), (3, 0, 5, ...));
+            /// ```
+            fn unwrap_or_all<B>(self, default: B) -> ($($other,)*)
+            where
+                Self: $trait<Item = Option<B>> + Sized,
+                B: Clone,
+            {
+                self.map(|x| x.unwrap_or_else(|| default.clone()))
+            }
+
+            /// Takes `(Result<a, e>, Result<a, e>, ...)` and unwraps each element,
+            /// computing a value from the error with `f` for `Err` elements.
+            /// # Example
+            /// ```ignore
+            /// let a: (Result<i32, &str>, ...) = (Ok(3), Err("bad"), Ok(5), ...);
+            /// assert_eq!(a.unwrap_or_else_each(|e| e.len() as i32), (3, 3, 5, ...));
+            /// ```
+            fn unwrap_or_else_each<B, E, F>(self, mut f: F) -> ($($other,)*)
+            where
+                Self: $trait<Item = Result<B, E>> + Sized,
+                F: FnMut(E) -> B,
+            {
+                self.map(|x| x.unwrap_or_else(&mut f))
+            }
+        }
+
+        impl<T> $trait for ($($self, )*) {
+            type Item = T;
+
+            fn all<F>(self, mut f: F) -> bool
+            where
+                F: FnMut(Self::Item) -> bool
+            {
+                let ($($name,)*) = self;
+                $(if !f($name) { return false } )*
+                true
+            }
+
+            fn any<F>(self, mut f: F) -> bool
+            where
+                F: FnMut(Self::Item) -> bool
+            {
+                let ($($name,)*) = self;
+                $(if f($name) { return true } )*
+                false
+            }
+
+            fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+            where
+                F: FnMut(&Self::Item) -> ::std::cmp::Ordering
+            {
+                use std::cmp::Ordering;
+                let ($(ref $name,)*) = *self;
+                let elems = [$($name,)*];
+                let (mut lo, mut hi) = (0, elems.len());
+                while lo < hi {
+                    let mid = lo + (hi - lo) / 2;
+                    match f(elems[mid]) {
+                        Ordering::Less => lo = mid + 1,
+                        Ordering::Greater => hi = mid,
+                        Ordering::Equal => return Ok(mid),
+                    }
+                }
+                Err(lo)
+            }
+
+            fn by_ref(&self) -> ($(&Self::$item, )*) {
+                let ($(ref $name,)*) = *self;
+                ($($name,)*)
+            }
+
+            fn by_ref_mut(&mut self) -> ($(&mut Self::$item, )*) {
+                let ($(ref mut $name,)*) = *self;
+                ($($name,)*)
+            }
+
+            fn call<R, F>(self, f: F) -> R
+            where
+                F: FnOnce($(Self::$item,)*) -> R
+            {
+                let ($($name,)*) = self;
+                f($($name,)*)
+            }
+
+            fn curry<R, G>(g: G) -> impl Fn($(Self::$item,)*) -> R
+            where
+                G: Fn(Self) -> R
+            {
+                move |$($name,)*| g(($($name,)*))
+            }
+
+            fn enumerate(self) -> ($((usize, Self::$item),)*) {
+                let ($($name,)*) = self;
+                ($(($idx, $name),)*)
+            }
+
+            fn find<F>(self, mut f: F) -> Option<Self::Item>
+            where
+                F: FnMut(&Self::Item) -> bool
+            {
+                let ($($name,)*) = self;
+                $(if f(&$name) { return Some($name) })*
+                None
+            }
+
+            fn fold<B, F>(self, mut init: B, mut f: F) -> B
+            where
+                F: FnMut(B, Self::Item) -> B
+            {
+                let ($($name,)*) = self;
+                $(init = f(init, $name);)*
+                init
+            }
+
+            fn fold_with_index<B, F>(self, mut init: B, mut f: F) -> B
+            where
+                F: FnMut(B, usize, Self::Item) -> B
+            {
+                let ($($name,)*) = self;
+                $(init = f(init, $idx, $name);)*
+                init
+            }
+
+            fn for_each<F>(self, mut f: F)
+            where
+                F: FnMut(Self::Item) -> ()
+            {
+                let ($($name,)*) = self;
+                $(f($name);)*
+            }
+
+            fn for_each_with_index<F>(self, mut f: F)
+            where
+                F: FnMut(usize, Self::Item)
+            {
+                let ($($name,)*) = self;
+                $(f($idx, $name);)*
+            }
+
+            fn from_fn<F>(mut f: F) -> Self
+            where
+                F: FnMut(usize) -> T,
+            {
+                ($(f($idx),)*)
+            }
+
+            fn get_many_mut<const N: usize>(
+                &mut self,
+                indices: [usize; N],
+            ) -> Result<[&mut Self::Item; N], $crate::error::GetManyMutError> {
+                let ($(ref mut $name,)*) = *self;
+                $crate::inplace::get_many_mut([$($name,)*], indices)
+            }
+
+            fn id(self) -> ($(Self::$item,)*) {
+                let ($($name,)*) = self;
+                ($($name,)*)
+            }
+
+            fn map<B, F>(self, mut f: F) -> ($($other, )*)
+            where
+                F: FnMut(Self::Item) -> B
+            {
+                let ($($name,)*) = self;
+                ($(f($name),)*)
+            }
+
+            fn map_with_index<B, F>(self, mut f: F) -> ($($other, )*)
+            where
+                F: FnMut(usize, Self::Item) -> B
+            {
+                let ($($name,)*) = self;
+                ($(f($idx, $name),)*)
+            }
+
+            fn nth(self, i: usize) -> Option<Self::Item> {
+                let ($($name,)*) = self;
+                let mut _cnt = 0;
+                $(if _cnt == i { return Some($name) } else { _cnt += 1 })*
+                None
+            }
+
+            fn nth_const<const I: usize>(self) -> Self::Item {
+                const {
+                    assert!(I < Self::ARITY, "index out of range for the tuple");
+                }
+                let ($($name,)*) = self;
+                match I {
+                    $($idx => $name,)*
+                    _ => unreachable!(),
+                }
+            }
+
+            #[allow(unused_mut, unused_variables, clippy::unused_unit)]
+            fn pairwise(self) -> ($(replace_ty!($name_reduced (Self::Item, Self::Item)),)*)
+            where
+                Self::Item: Clone
+            {
+                let ($($name,)*) = self;
+                let mut elems = IntoIterator::into_iter([$($name,)*]);
+                let mut prev = elems.next();
+                // Each interior element is cloned once, and the originals are moved.
+                ($(replace_expr!($name_reduced {
+                    let next = elems.next().unwrap();
+                    if elems.len() == 0 {
+                        (prev.take().unwrap(), next)
+                    } else {
+                        (prev.replace(next.clone()).unwrap(), next)
+                    }
+                }),)*)
+            }
+
+            fn position<F>(self, mut f: F) -> Option<usize>
+            where
+                F: FnMut(&Self::Item) -> bool
+            {
+                let ($($name,)*) = self;
+                $(if f(&$name) { return Some($idx) })*
+                None
+            }
+
+            #[allow(unused_mut, unused_variables)]
+            fn reduce<F>(self, mut f: F) -> Self::Item
+            where
+                F: FnMut(Self::Item, Self::Item) -> Self::Item
+            {
+                let (mut acc, $($name_reduced,)*) = self;
+                $(acc = f(acc, $name_reduced);)*
+                acc
+            }
+
+            fn rev(self) -> ($(Self::$item,)*) {
+                let ($($name,)*) = self;
+                let mut elems = [$($name,)*];
+                elems.reverse();
+                let [$($name,)*] = elems;
+                ($($name,)*)
+            }
+
+            fn reverse_mut(&mut self) {
+                let ($(ref mut $name,)*) = *self;
+                $crate::inplace::reverse(&mut [$($name,)*]);
+            }
+
+            fn rfind<F>(self, mut f: F) -> Option<Self::Item>
+            where
+                F: FnMut(&Self::Item) -> bool
+            {
+                let ($($name,)*) = self;
+                IntoIterator::into_iter([$($name,)*]).rev().find(|x| f(x))
+            }
+
+            fn rfold<B, F>(self, init: B, f: F) -> B
+            where
+                F: FnMut(B, Self::Item) -> B
+            {
+                let ($($name,)*) = self;
+                IntoIterator::into_iter([$($name,)*]).rfold(init, f)
+            }
+
+            fn rotate_left<const K: usize>(self) -> ($(Self::$item,)*) {
+                let ($($name,)*) = self;
+                let mut elems = [$($name,)*];
+                let len = elems.len();
+                elems[..].rotate_left(K % len);
+                let [$($name,)*] = elems;
+                ($($name,)*)
+            }
+
+            fn rotate_left_mut(&mut self, k: usize) {
+                let ($(ref mut $name,)*) = *self;
+                $crate::inplace::rotate_left(&mut [$($name,)*], k);
+            }
+
+            fn rotate_right<const K: usize>(self) -> ($(Self::$item,)*) {
+                let ($($name,)*) = self;
+                let mut elems = [$($name,)*];
+                let len = elems.len();
+                elems[..].rotate_right(K % len);
+                let [$($name,)*] = elems;
+                ($($name,)*)
+            }
+
+            fn rotate_right_mut(&mut self, k: usize) {
+                let ($(ref mut $name,)*) = *self;
+                $crate::inplace::rotate_right(&mut [$($name,)*], k);
+            }
+
+            fn rposition<F>(self, mut f: F) -> Option<usize>
+            where
+                F: FnMut(&Self::Item) -> bool
+            {
+                let ($($name,)*) = self;
+                IntoIterator::into_iter([$($name,)*]).rposition(|x| f(&x))
+            }
+
+            #[allow(unused_variables)]
+            fn same(self) -> bool
+            where
+                Self::Item: PartialEq
+            {
+                let (first, $($name_reduced,)*) = self;
+                $(if $name_reduced != first { return false } )*
+                true
+            }
+
+            fn same_as(self, i: Self::Item) -> bool
+            where
+                Self::Item: PartialEq
+            {
+                let ($($name,)*) = self;
+                $(if $name != i { return false })*
+                true
+            }
+
+            fn sorted_total(self) -> ($(Self::$item,)*)
+            where
+                Self::Item: $crate::num::TotalOrd
+            {
+                let ($($name,)*) = self;
+                let mut arr = [$($name,)*];
+                arr.sort_unstable_by($crate::num::TotalOrd::total_cmp);
+                let [$($name,)*] = arr;
+                ($($name,)*)
+            }
+
+            fn spawn_map<B, F>(self, f: F) -> ($($other,)*)
+            where
+                F: Fn(Self::Item) -> B + Sync,
+                Self::Item: Send,
+                B: Send
+            {
+                let ($($name,)*) = self;
+                let f = &f;
+                ::std::thread::scope(|s| {
+                    $(let $name = s.spawn(move || f($name));)*
+                    ($(match $name.join() {
+                        Ok(x) => x,
+                        Err(e) => ::std::panic::resume_unwind(e),
+                    },)*)
+                })
+            }
+
+            fn splat(x: T) -> Self
+            where
+                T: Clone,
+            {
+                ($(replace_expr!($name_reduced x.clone()),)* x,)
+            }
+
+            fn swap(&mut self, i: usize, j: usize) {
+                let ($(ref mut $name,)*) = *self;
+                $crate::inplace::swap(&mut [$($name,)*], i, j);
+            }
+
+            fn swap_with(&mut self, other: &mut Self) {
+                $(::std::mem::swap(&mut self.$idx, &mut other.$idx);)*
+            }
+
+            #[allow(unused_mut)]
+            fn tmax(self) -> Self::Item
+            where
+                Self::Item: ::std::cmp::PartialOrd
+            {
+                let (mut acc, $($name_reduced,)*) = self;
+                $(if acc < $name_reduced {
+                    acc = $name_reduced;
+                })*
+                acc
+            }
+
+            #[allow(unused_mut)]
+            fn tmin(self) -> Self::Item
+            where
+                Self::Item: ::std::cmp::PartialOrd
+            {
+                let (mut acc, $($name_reduced,)*) = self;
+                $(if acc > $name_reduced {
+                    acc = $name_reduced;
+                })*
+                acc
+            }
+
+            fn try_fold<B, E, F>(self, mut init: B, mut f: F) -> Result<B, E>
+            where
+                F: FnMut(B, Self::Item) -> Result<B, E>
+            {
+                let ($($name,)*) = self;
+                $(init = f(init, $name)?;)*
+                Ok(init)
+            }
+
+            fn try_for_each<E, F>(self, mut f: F) -> Result<(), E>
+            where
+                F: FnMut(Self::Item) -> Result<(), E>
+            {
+                let ($($name,)*) = self;
+                $(f($name)?;)*
+                Ok(())
+            }
+
+            fn try_from_iter<I>(iter: I) -> Option<Self>
+            where
+                I: IntoIterator<Item = T>,
+            {
+                let mut iter = iter.into_iter();
+                $(let $name = iter.next()?;)*
+                match iter.next() {
+                    Some(_) => None,
+                    None => Some(($($name,)*)),
+                }
+            }
+
+            fn try_from_slice(s: &[T]) -> Result<Self, $crate::error::LenError>
+            where
+                T: Clone,
+            {
+                match *s {
+                    [$(ref $name,)*] => Ok(($($name.clone(),)*)),
+                    _ => Err($crate::error::LenError {
+                        expected: Self::ARITY,
+                        actual: s.len(),
+                    }),
+                }
+            }
+
+            fn try_from_vec(v: Vec<T>) -> Result<Self, $crate::error::LenError> {
+                let actual = v.len();
+                Self::try_from_iter(v).ok_or($crate::error::LenError {
+                    expected: Self::ARITY,
+                    actual,
+                })
+            }
+
+            fn try_zipf<U, I, F, B, E>(self, other: U, mut f: F) -> Result<($($other,)*), E>
+            where
+                U: $trait<Item = I>,
+                F: FnMut(Self::Item, I) -> Result<B, E>
+            {
+                let ($($name,)*) = self;
+                let ($($name2,)*) = other.id();
+                Ok(($(f($name, $name2)?,)*))
+            }
+
+            #[allow(clippy::unused_unit)]
+            fn uncons(self) -> (Self::Item, ($(replace_ty!($name_reduced Self::Item),)*)) {
+                let (first, $($name_reduced,)*) = self;
+                (first, ($($name_reduced,)*))
+            }
+
+            #[allow(clippy::unused_unit)]
+            fn unsnoc(self) -> (($(replace_ty!($name_reduced Self::Item),)*), Self::Item) {
+                let ($($name,)*) = self;
+                let [$($name_reduced,)* last] = [$($name,)*];
+                (($($name_reduced,)*), last)
+            }
+
+            fn zip<U, B>(self, other: U) -> ($((Self::$item, $other),)*)
+            where
+                U: $trait<Item = B>
+            {
+                let ($($name,)*) = self;
+                let ($($name2,)*) = other.id();
+                ($(($name, $name2),)*)
+            }
+
+            fn zipf<U, I, F, B>(self, other: U, mut f: F) -> ($($other,)*)
+            where
+                U: $trait<Item = I>,
+                F: FnMut(Self::Item, I) -> B
+            {
+                let ($($name,)*) = self;
+                let ($($name2,)*) = other.id();
+                ($(f($name, $name2),)*)
+            }
+        }
+
+        impl<T> $arith for ($($self, )*) {
+            #[allow(unused_mut)]
+            fn sum(self) -> Self::Item
+            where
+                Self::Item: ::std::ops::AddAssign
+            {
+                let (mut acc, $($name_reduced,)*) = self;
+                $(acc += $name_reduced;)*
+                acc
+            }
+
+            #[allow(unused_mut)]
+            fn product(self) -> Self::Item
+            where
+                Self::Item: ::std::ops::MulAssign
+            {
+                let (mut acc, $($name_reduced,)*) = self;
+                $(acc *= $name_reduced;)*
+                acc
+            }
+
+            fn summary(self) -> TupleSummary<Self::Item>
+            where
+                Self::Item: Clone + ::std::cmp::PartialOrd + ::std::ops::AddAssign + $crate::num::ToF64
+            {
+                let (first, $($name_reduced,)*) = self;
+                let mut summary = TupleSummary {
+                    min: first.clone(),
+                    max: first.clone(),
+                    sum: first,
+                    mean: 0.0,
+                    count: 1,
+                };
+                $(
+                    if $name_reduced < summary.min {
+                        summary.min = $name_reduced.clone();
+                    }
+                    if $name_reduced > summary.max {
+                        summary.max = $name_reduced.clone();
+                    }
+                    summary.sum += $name_reduced;
+                    summary.count += 1;
+                )*
+                summary.mean = $crate::num::ToF64::to_f64(summary.sum.clone()) / summary.count as f64;
+                summary
+            }
+
+            fn linspace(start: T, end: T) -> Self
+            where
+                T: Copy + $crate::num::Linspace,
+            {
+                let len = Self::ARITY;
+                Self::from_fn(|i| $crate::num::Linspace::linspace_nth(start, end, i, len))
+            }
+        }
+
+        impl<T> $convert for ($($self, )*) {
+            fn into_array(self) -> [T; 0 $(+ replace_expr!($idx 1))*] {
+                let ($($name,)*) = self;
+                [$($name,)*]
+            }
+
+            fn into_vec(self) -> Vec<Self::Item> {
+                let ($($name,)*) = self;
+                vec![$($name,)*]
+            }
+
+            #[allow(unused_mut, unused_variables)]
+            fn join(self, sep: &str) -> String
+            where
+                Self::Item: AsRef<str>
+            {
+                let (first, $($name_reduced,)*) = self;
+                let mut s = first.as_ref().to_owned();
+                $(
+                    s.push_str(sep);
+                    s.push_str($name_reduced.as_ref());
+                )*
+                s
+            }
+
+            fn try_lock_all<'a>(&'a self) -> Option<($(<Self::$item as $crate::sync::Lock<'a>>::Guard,)*)>
+            where
+                Self::Item: $crate::sync::Lock<'a>
+            {
+                let ($(ref $name,)*) = *self;
+                Some(($($crate::sync::Lock::try_lock($name)?,)*))
+            }
+        }
+
+        impl<T> $crate::fns::Tuple for ($($self, )*) {
+            type Item = T;
+            type Mapped<B> = ($($other, )*);
+            const ARITY: usize = <Self as $trait>::ARITY;
+
+            fn by_ref(&self) -> Self::Mapped<&Self::Item> {
+                $trait::by_ref(self)
+            }
+
+            fn by_ref_mut(&mut self) -> Self::Mapped<&mut Self::Item> {
+                $trait::by_ref_mut(self)
+            }
+
+            fn fold<B, F>(self, init: B, f: F) -> B
+            where
+                F: FnMut(B, Self::Item) -> B
+            {
+                $trait::fold(self, init, f)
+            }
+
+            fn map<B, F>(self, f: F) -> Self::Mapped<B>
+            where
+                F: FnMut(Self::Item) -> B
+            {
+                $trait::map(self, f)
+            }
+
+            fn zip_with<U, B, F>(self, other: Self::Mapped<U>, f: F) -> Self::Mapped<B>
+            where
+                F: FnMut(Self::Item, U) -> B
+            {
+                $trait::zipf(self, other, f)
+            }
+
+            fn from_fn<F>(f: F) -> Self
+            where
+                F: FnMut(usize) -> Self::Item
+            {
+                $trait::from_fn(f)
+            }
+
+            fn try_from_iter<I>(iter: I) -> Option<Self>
+            where
+                I: IntoIterator<Item = Self::Item>
+            {
+                $trait::try_from_iter(iter)
+            }
+        }
+
+        impl<T> $crate::fns::Tuple for [T; 0 $(+ replace_expr!($idx 1))*] {
+            type Item = T;
+            type Mapped<B> = [B; 0 $(+ replace_expr!($idx 1))*];
+            const ARITY: usize = <($($self,)*) as $trait>::ARITY;
+
+            fn by_ref(&self) -> Self::Mapped<&Self::Item> {
+                self.each_ref()
+            }
+
+            fn by_ref_mut(&mut self) -> Self::Mapped<&mut Self::Item> {
+                self.each_mut()
+            }
+
+            fn fold<B, F>(self, init: B, f: F) -> B
+            where
+                F: FnMut(B, Self::Item) -> B
+            {
+                IntoIterator::into_iter(self).fold(init, f)
+            }
+
+            fn map<B, F>(self, f: F) -> Self::Mapped<B>
+            where
+                F: FnMut(Self::Item) -> B
+            {
+                <[T; 0 $(+ replace_expr!($idx 1))*]>::map(self, f)
+            }
+
+            fn zip_with<U, B, F>(self, other: Self::Mapped<U>, mut f: F) -> Self::Mapped<B>
+            where
+                F: FnMut(Self::Item, U) -> B
+            {
+                let [$($name,)*] = self;
+                let [$($name2,)*] = other;
+                [$(f($name, $name2),)*]
+            }
+
+            fn from_fn<F>(f: F) -> Self
+            where
+                F: FnMut(usize) -> Self::Item
+            {
+                ::std::array::from_fn(f)
+            }
+
+            fn try_from_iter<I>(iter: I) -> Option<Self>
+            where
+                I: IntoIterator<Item = Self::Item>
+            {
+                <($($self,)*) as $trait>::try_from_iter(iter).map($convert::into_array)
+            }
+        }
+
+        impl<'a, T: 'a> $crate::cell::AsCells<'a> for ::std::cell::Cell<($($self, )*)> {
+            type Output = ($(&'a ::std::cell::Cell<$self>, )*);
+
+            fn as_cells(&'a self) -> Self::Output {
+                let ptr = self.as_ptr();
+                // This is safe because `Cell<T>` has the same memory layout as `T`, and
+                // we never create references to the whole tuple.
+                unsafe {
+                    ($(&*(::std::ptr::addr_of_mut!((*ptr).$idx) as *const ::std::cell::Cell<T>), )*)
+                }
+            }
+        }
+    };
+}
+
+impl_tuple_map!{
+    TupleMap1,
+    TupleArith1,
+    TupleConvert1,
+    ,
+    a,
     a2,
     Item,
     T,
-    B
+    B,
+    0
 }
 impl_tuple_map!{
     TupleMap2,
+    TupleArith2,
+    TupleConvert2,
     b,
     a b,
     a2 b2,
     Item Item,
     T T,
-    B B
+    B B,
+    0 1
 }
 impl_tuple_map!{
     TupleMap3,
+    TupleArith3,
+    TupleConvert3,
     b c,
     a b c,
     a2 b2 c2,
     Item Item Item,
     T T T,
-    B B B
+    B B B,
+    0 1 2
 }
 impl_tuple_map!{
     TupleMap4,
+    TupleArith4,
+    TupleConvert4,
     b c d,
     a b c d,
     a2 b2 c2 d2,
     Item Item Item Item,
     T T T T,
-    B B B B
+    B B B B,
+    0 1 2 3
 }
 impl_tuple_map!{
     TupleMap5,
+    TupleArith5,
+    TupleConvert5,
     b c d e,
     a b c d e,
     a2 b2 c2 d2 e2,
     Item Item Item Item Item,
     T T T T T,
-    B B B B B
+    B B B B B,
+    0 1 2 3 4
 }
 impl_tuple_map!{
     TupleMap6,
+    TupleArith6,
+    TupleConvert6,
     b c d e f,
     a b c d e f,
     a2 b2 c2 d2 e2 f2,
     Item Item Item Item Item Item,
     T T T T T T,
-    B B B B B B
+    B B B B B B,
+    0 1 2 3 4 5
 }
 impl_tuple_map!{
     TupleMap7,
+    TupleArith7,
+    TupleConvert7,
     b c d e f g,
     a b c d e f g,
     a2 b2 c2 d2 e2 f2 g2,
     Item Item Item Item Item Item Item,
     T T T T T T T,
-    B B B B B B B
+    B B B B B B B,
+    0 1 2 3 4 5 6
 }
 impl_tuple_map!{
     TupleMap8,
+    TupleArith8,
+    TupleConvert8,
     b c d e f g h,
     a b c d e f g h,
     a2 b2 c2 d2 e2 f2 g2 h2,
     Item Item Item Item Item Item Item Item,
     T T T T T T T T,
-    B B B B B B B B
+    B B B B B B B B,
+    0 1 2 3 4 5 6 7
 }
 impl_tuple_map!{
     TupleMap9,
+    TupleArith9,
+    TupleConvert9,
     b c d e f g h i,
     a b c d e f g h i,
     a2 b2 c2 d2 e2 f2 g2 h2 i2,
     Item Item Item Item Item Item Item Item Item,
     T T T T T T T T T,
-    B B B B B B B B B
+    B B B B B B B B B,
+    0 1 2 3 4 5 6 7 8
 }
 impl_tuple_map!{
     TupleMap10,
+    TupleArith10,
+    TupleConvert10,
     b c d e f g h i j,
     a b c d e f g h i j,
     a2 b2 c2 d2 e2 f2 g2 h2 i2 j2,
     Item Item Item Item Item Item Item Item Item Item,
     T T T T T T T T T T,
-    B B B B B B B B B B
+    B B B B B B B B B B,
+    0 1 2 3 4 5 6 7 8 9
 }
 impl_tuple_map!{
     TupleMap11,
+    TupleArith11,
+    TupleConvert11,
     b c d e f g h i j k,
     a b c d e f g h i j k,
     a2 b2 c2 d2 e2 f2 g2 h2 i2 j2 k2,
     Item Item Item Item Item Item Item Item Item Item Item,
     T T T T T T T T T T T,
-    B B B B B B B B B B B
+    B B B B B B B B B B B,
+    0 1 2 3 4 5 6 7 8 9 10
 }
 impl_tuple_map!{
     TupleMap12,
+    TupleArith12,
+    TupleConvert12,
     b c d e f g h i j k l,
     a b c d e f g h i j k l,
     a2 b2 c2 d2 e2 f2 g2 h2 i2 j2 k2 l2,
     Item Item Item Item Item Item Item Item Item Item Item Item,
     T T T T T T T T T T T T,
-    B B B B B B B B B B B B
+    B B B B B B B B B B B B,
+    0 1 2 3 4 5 6 7 8 9 10 11
 }
 impl_tuple_map!{
     TupleMap13,
+    TupleArith13,
+    TupleConvert13,
     b c d e f g h i j k l m,
     a b c d e f g h i j k l m,
     a2 b2 c2 d2 e2 f2 g2 h2 i2 j2 k2 l2 m2,
     Item Item Item Item Item Item Item Item Item Item Item Item Item,
     T T T T T T T T T T T T T,
-    B B B B B B B B B B B B B
+    B B B B B B B B B B B B B,
+    0 1 2 3 4 5 6 7 8 9 10 11 12
 }
 impl_tuple_map!{
     TupleMap14,
+    TupleArith14,
+    TupleConvert14,
     b c d e f g h i j k l m n,
     a b c d e f g h i j k l m n,
     a2 b2 c2 d2 e2 f2 g2 h2 i2 j2 k2 l2 m2 n2,
     Item Item Item Item Item Item Item Item Item Item Item Item Item Item,
     T T T T T T T T T T T T T T,
-    B B B B B B B B B B B B B B
+    B B B B B B B B B B B B B B,
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13
 }
 impl_tuple_map!{
     TupleMap15,
+    TupleArith15,
+    TupleConvert15,
     b c d e f g h i j k l m n o,
     a b c d e f g h i j k l m n o,
     a2 b2 c2 d2 e2 f2 g2 h2 i2 j2 k2 l2 m2 n2 o2,
     Item Item Item Item Item Item Item Item Item Item Item Item Item Item Item,
     T T T T T T T T T T T T T T T,
-    B B B B B B B B B B B B B B B
+    B B B B B B B B B B B B B B B,
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 14
 }
 impl_tuple_map!{
     TupleMap16,
+    TupleArith16,
+    TupleConvert16,
     b c d e f g h i j k l m n o p,
     a b c d e f g h i j k l m n o p,
     a2 b2 c2 d2 e2 f2 g2 h2 i2 j2 k2 l2 m2 n2 o2 p2,
     Item Item Item Item Item Item Item Item Item Item Item Item Item Item Item Item,
     T T T T T T T T T T T T T T T T,
-    B B B B B B B B B B B B B B B B
+    B B B B B B B B B B B B B B B B,
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+}
+
+/// Methods only for tuples whose length is even, like `(T, T)`, `(T, T, T, T)`, ...
+pub trait TupleMapEven {
+    type Item;
+    /// The tuple of pairs returned by [`chunks2`](#tymethod.chunks2).
+    type Chunks2;
+
+    /// Takes `(a, b, c, d, ...)` and groups its elements into non-overlapping pairs,
+    /// `((a, b), (c, d), ...)`.
+    /// # Example
+    /// ```
+    /// # use tuple_map::*;
+    /// let a = (1, 2, 3, 4);
+    /// assert_eq!(a.chunks2(), ((1, 2), (3, 4)));
+    /// ```
+    fn chunks2(self) -> Self::Chunks2;
+
+    /// Takes a closure `f` and applies it to every non-overlapping pair of elements,
+    /// `(a, b)`, `(c, d)`, ..., and produce single value.
+    /// # Example
+    /// ```
+    /// # use tuple_map::*;
+    /// let a = ("x", "1", "y", "2");
+    /// let s = a.fold_chunks2(String::new(), |s, key, value| s + key + "=" + value + ";");
+    /// assert_eq!(s, "x=1;y=2;");
+    /// ```
+    fn fold_chunks2<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item, Self::Item) -> B;
+
+    /// Takes `(a, b, c, d, ...)` and exchanges the first half and the second half.
+    /// # Example
+    /// ```
+    /// # use tuple_map::*;
+    /// let a = (1, 2, 3, 4);
+    /// assert_eq!(a.swap_halves(), (3, 4, 1, 2));
+    /// ```
+    fn swap_halves(self) -> Self;
+}
+
+macro_rules! impl_tuple_map_even {
+    ($($front: ident)+,
+     $($back: ident)+,
+     $(($first: ident $second: ident))+,
+     $($self: ident)+) => {
+        impl<T> TupleMapEven for ($($self, )*) {
+            type Item = T;
+            type Chunks2 = ($(replace_ty!($first (T, T)),)*);
+
+            fn chunks2(self) -> Self::Chunks2 {
+                let ($($first, $second,)*) = self;
+                ($(($first, $second),)*)
+            }
+
+            fn fold_chunks2<B, F>(self, mut init: B, mut f: F) -> B
+            where
+                F: FnMut(B, Self::Item, Self::Item) -> B
+            {
+                let ($($first, $second,)*) = self;
+                $(init = f(init, $first, $second);)*
+                init
+            }
+
+            fn swap_halves(self) -> Self {
+                let ($($front,)* $($back,)*) = self;
+                ($($back,)* $($front,)*)
+            }
+        }
+    };
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl_tuple_map_even!{
+    a,
+    b,
+    (a b),
+    T T
+}
+impl_tuple_map_even!{
+    a b,
+    c d,
+    (a b) (c d),
+    T T T T
+}
+impl_tuple_map_even!{
+    a b c,
+    d e f,
+    (a b) (c d) (e f),
+    T T T T T T
+}
+impl_tuple_map_even!{
+    a b c d,
+    e f g h,
+    (a b) (c d) (e f) (g h),
+    T T T T T T T T
+}
+impl_tuple_map_even!{
+    a b c d e,
+    f g h i j,
+    (a b) (c d) (e f) (g h) (i j),
+    T T T T T T T T T T
+}
+impl_tuple_map_even!{
+    a b c d e f,
+    g h i j k l,
+    (a b) (c d) (e f) (g h) (i j) (k l),
+    T T T T T T T T T T T T
+}
+impl_tuple_map_even!{
+    a b c d e f g,
+    h i j k l m n,
+    (a b) (c d) (e f) (g h) (i j) (k l) (m n),
+    T T T T T T T T T T T T T T
+}
+impl_tuple_map_even!{
+    a b c d e f g h,
+    i j k l m n o p,
+    (a b) (c d) (e f) (g h) (i j) (k l) (m n) (o p),
+    T T T T T T T T T T T T T T T T
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all() {
+        let a = (3, 9, 12, 15);
+        assert!(a.all(|x| x % 3 == 0));
+        assert!(!a.all(|x| x % 4 == 0));
+    }
+
+    #[test]
+    fn test_and_then_each() {
+        let a = (Some(4), None, Some(-1));
+        let b = a.and_then_each(|x| if x > 0 { Some(x * 2) } else { None });
+        assert_eq!(b, (Some(8), None, None));
+    }
+
+    #[test]
+    fn test_any() {
+        let a = (3, 9, 12, 15);
+        assert!(a.any(|x| x % 4 == 0));
+        assert!(!a.any(|x| x % 7 == 0));
+    }
+
+    #[test]
+    fn test_as_cells() {
+        use cell::AsCells;
+        use std::cell::Cell;
+        let a = Cell::new((3, 4, 5));
+        let (x, y, _) = a.as_cells();
+        x.set(y.get() * 2);
+        y.set(0);
+        assert_eq!(a.get(), (8, 0, 5));
+        let mut b = (1, 2);
+        Cell::from_mut(&mut b).as_cells().map(|c| c.set(c.get() + 1));
+        assert_eq!(b, (2, 3));
+    }
+
+    #[test]
+    fn test_binary_search() {
+        let a = (1, 3, 5, 7, 9);
+        assert_eq!(a.binary_search(&1), Ok(0));
+        assert_eq!(a.binary_search(&9), Ok(4));
+        assert_eq!(a.binary_search(&4), Err(2));
+        assert_eq!(a.binary_search(&10), Err(5));
+        assert_eq!((2,).binary_search(&1), Err(0));
+    }
+
+    #[test]
+    fn test_binary_search_by() {
+        let a = ((0, 'a'), (2, 'b'), (4, 'c'));
+        assert_eq!(a.binary_search_by(|&(k, _)| k.cmp(&2)), Ok(1));
+        assert_eq!(a.binary_search_by(|&(k, _)| k.cmp(&3)), Err(2));
+    }
+
+    #[test]
+    fn test_by_ref() {
+        let a = (3, 3, 3);
+        let b = a.by_ref();
+        assert_eq!(b, (&3, &3, &3))
+    }
+
+    #[test]
+    fn test_by_ref_mut() {
+        let mut a = (3, 3, 3);
+        assert_eq!(a.by_ref_mut(), (&mut 3, &mut 3, &mut 3));
+        a.by_ref_mut().for_each(|x| *x += 5);
+        assert_eq!(a, (8, 8, 8))
+    }
+
+    #[test]
+    fn test_call() {
+        assert_eq!((3, 4, 5).call(|x, y, z| x * y + z), 17);
+        assert_eq!((3, 4).call(i32::max), 4);
+        let a = ("a".to_owned(), "b".to_owned()).call(|x, y| x + &y);
+        assert_eq!(a, "ab");
+        assert_eq!((1, 2, 3).map(|x| x * 2).call(|x, y, z| [z, y, x]), [6, 4, 2]);
+    }
+
+    #[test]
+    fn test_curry() {
+        let f = <(i32, i32, i32)>::curry(|(x, y, z)| x * y + z);
+        assert_eq!(f(3, 4, 5), 17);
+        let g = <(i32, i32)>::curry(|(x, y)| x - y);
+        assert_eq!(g(5, 3), 2);
+        let h = <(i32, i32, i32)>::uncurry(|x, y, z| x * y + z);
+        assert_eq!(h((3, 4, 5)), 17);
+        let v: Vec<_> = vec![(1, 2), (3, 4)]
+            .into_iter()
+            .map(<(i32, i32)>::uncurry(i32::max))
+            .collect();
+        assert_eq!(v, vec![2, 4]);
+    }
+
+    #[test]
+    fn test_cloned() {
+        let mut a = (3, 3, 3);
+        assert_eq!(a, a.cloned());
+        let b = a.cloned().map(|x| x * 3);
+        a.by_ref_mut().for_each(|x| *x *= 3);
+        assert_eq!(b, a.cloned())
+    }
+
+    #[test]
+    fn test_downgrade() {
+        let mut a = (3, 4, 5);
+        let b = a.by_ref_mut();
+        assert_eq!(b.downgrade(), (&3, &4, &5));
+    }
+
+    #[test]
+    fn test_expect_all() {
+        assert_eq!((Some(3), Some(4)).expect_all("missing"), (3, 4));
+    }
+
+    #[test]
+    #[should_panic(expected = "missing: `None` value at index 1")]
+    fn test_expect_all_none() {
+        (Some(3), None, Some(5)).expect_all("missing");
+    }
+
+    #[test]
+    fn test_fns() {
+        fn sum_of_squares<T: fns::Tuple<Item = i32>>(t: T) -> i32 {
+            fns::sum(fns::map(t, |x| x * x))
+        }
+        assert_eq!(sum_of_squares((1, 2)), 5);
+        assert_eq!(sum_of_squares((1, 2, 3)), 14);
+        assert_eq!(fns::zip((1, 2), ("a", "b")), ((1, "a"), (2, "b")));
+    }
+
+    #[test]
+    fn test_enumerate() {
+        let a = ("a", "b", "c");
+        assert_eq!(a.enumerate(), ((0, "a"), (1, "b"), (2, "c")));
+    }
+
+    #[test]
+    fn test_filter() {
+        let a = (3, 4, 5, 6);
+        assert_eq!(a.filter(|&x| x % 2 == 0), vec![4, 6]);
+        assert!(a.filter(|&x| x > 6).is_empty());
+    }
+
+    #[test]
+    fn test_filter_map() {
+        let a = ("3", "x", "5");
+        assert_eq!(a.filter_map(|s| s.parse::<i32>().ok()), vec![3, 5]);
+    }
+
+    #[test]
+    fn test_concat() {
+        assert_eq!((1, 2).concat((3, 4, 5)), (1, 2, 3, 4, 5));
+        assert_eq!((1,).concat((2,)).concat((3, 4)), (1, 2, 3, 4));
+    }
+
+    #[test]
+    fn test_count_matches() {
+        let a = (3, 4, 5, 6);
+        assert_eq!(a.count_matches(|&x| x % 2 == 0), 2);
+        assert_eq!(a.count_matches(|&x| x > 6), 0);
+    }
+
+    #[test]
+    fn test_find() {
+        let mut a = (3, 3, 5, 3);
+        if let Some(x) = a.by_ref_mut().find(|&&mut x| x == 5) {
+            *x = 3
+        }
+        assert!(a.same());
+    }
+
+    #[test]
+    fn test_flatten() {
+        assert_eq!(((1, 2), (3, 4), (5, 6)).flatten(), (1, 2, 3, 4, 5, 6));
+        assert_eq!(((1, 2, 3),).flatten(), (1, 2, 3));
+        let a = (1, 2, 3, 4, 5, 6, 7, 8);
+        assert_eq!(a.chunks2().flatten(), a);
+    }
+
+    #[test]
+    fn test_fold() {
+        let a = (3, 3, 3, 3);
+        let sum = a.fold(0, |sum, x| sum + x);
+        assert_eq!(sum, 12)
+    }
+
+    #[test]
+    fn test_fold_with_index() {
+        let a = (3, 4, 5);
+        assert_eq!(a.fold_with_index(0, |sum, i, x| sum + i * x), 14);
+    }
+
+    #[test]
+    fn test_collect() {
+        use std::collections::{BTreeSet, HashSet};
+        let a: BTreeSet<_> = (3, 1, 3).collect();
+        assert_eq!(a.into_iter().collect::<Vec<_>>(), vec![1, 3]);
+        let b: HashSet<_> = ("x", "y", "x").collect();
+        assert_eq!(b.len(), 2);
+        let c: String = ("ab", "c").collect();
+        assert_eq!(c, "abc");
+    }
+
+    #[test]
+    fn test_collect_string() {
+        assert_eq!(('a', 'b', 'c').collect_string(), "abc");
+    }
+
+    #[test]
+    fn test_concat_str() {
+        assert_eq!(("a", "b", "c").concat_str(), "abc");
+        assert_eq!(("a".to_owned(), "b".to_owned()).concat_str(), "ab");
+    }
+
+    #[test]
+    fn test_format_each() {
+        let a = (1.5, 2.25, 3.0);
+        let b = a.format_each(|x| format!("{:>6.2}", x));
+        assert_eq!(b, ("  1.50".to_owned(), "  2.25".to_owned(), "  3.00".to_owned()));
+    }
+
+    #[test]
+    fn test_for_each_with_index() {
+        let a = ("a", "b", "c");
+        let mut v = vec![];
+        a.for_each_with_index(|i, x| v.push(format!("{}{}", i, x)));
+        assert_eq!(v, vec!["0a", "1b", "2c"]);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let a: (usize, usize, usize) = TupleMap3::from_fn(|i| i * 2);
+        assert_eq!(a, (0, 2, 4));
+        let mut calls = Vec::new();
+        let b: (String, String) = TupleMap2::from_fn(|i| {
+            calls.push(i);
+            i.to_string()
+        });
+        assert_eq!(b, ("0".to_owned(), "1".to_owned()));
+        assert_eq!(calls, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_get() {
+        let mut a = (3, 4, 5);
+        assert_eq!(a.get(1), Some(&4));
+        assert_eq!(a.get(3), None);
+        if let Some(x) = a.get_mut(1) {
+            *x = 10;
+        }
+        assert_eq!(a, (3, 10, 5));
+        assert!(a.get_mut(3).is_none());
+        let mut b = ("a".to_owned(),);
+        b.get_mut(0).unwrap().push('b');
+        assert_eq!(b.get(0).map(String::as_str), Some("ab"));
+    }
+
+    #[test]
+    fn test_set_replace() {
+        use error::OutOfRange;
+        let mut a = (3, 4, 5);
+        assert_eq!(a.set(1, 10), Ok(()));
+        assert_eq!(a, (3, 10, 5));
+        assert_eq!(a.set(3, 0), Err(OutOfRange { index: 3, len: 3 }));
+        assert_eq!(a.replace(2, 6), Some(5));
+        assert_eq!(a.replace(3, 7), None);
+        assert_eq!(a, (3, 10, 6));
+        let mut b = ("a".to_owned(), "b".to_owned());
+        assert_eq!(b.replace(0, "c".to_owned()), Some("a".to_owned()));
+        assert_eq!(b, ("c".to_owned(), "b".to_owned()));
+    }
+
+    #[test]
+    fn test_get_many_mut() {
+        use error::GetManyMutError;
+        let mut a = (3, 4, 5);
+        {
+            let [x, y] = a.get_many_mut([0, 2]).unwrap();
+            ::std::mem::swap(x, y);
+        }
+        assert_eq!(a, (5, 4, 3));
+        assert_eq!(
+            a.get_many_mut([1, 1]),
+            Err(GetManyMutError::OverlappingIndices { index: 1 })
+        );
+        assert_eq!(
+            a.get_many_mut([3]),
+            Err(GetManyMutError::IndexOutOfBounds { index: 3, len: 3 })
+        );
+    }
+
+    #[test]
+    fn test_into_array() {
+        let mut a = (3, 1, 2).into_array();
+        a.sort();
+        assert_eq!(a, [1, 2, 3]);
+        assert_eq!(("a".to_owned(),).into_array(), ["a".to_owned()]);
+        assert_eq!((1, 2, 3, 4).into_array().chunks(2).count(), 2);
+    }
+
+    #[test]
+    fn test_into_boxed_slice() {
+        let a = (3, 4, 5).into_boxed_slice();
+        assert_eq!(&*a, &[3, 4, 5]);
+        let b = ("a".to_owned(),).into_boxed_slice();
+        assert_eq!(b.len(), 1);
+    }
+
+    #[test]
+    fn test_into_tuple_iter() {
+        let mut sum = 0;
+        for x in (3, 4, 5).into_tuple_iter() {
+            sum += x;
+        }
+        assert_eq!(sum, 12);
+        let v: Vec<_> = ("a", "b").into_tuple_iter().map(str::to_uppercase).collect();
+        assert_eq!(v, vec!["A".to_owned(), "B".to_owned()]);
+        let mut it = (1, 2).into_tuple_iter();
+        assert_eq!(it.size_hint(), (2, Some(2)));
+        it.next();
+        assert_eq!(it.size_hint(), (1, Some(1)));
+    }
+
+    #[test]
+    fn test_tuple_iter_double_ended() {
+        let mut it = (1, 2, 3, 4).into_tuple_iter();
+        assert_eq!(it.len(), 4);
+        assert_eq!(it.next_back(), Some(4));
+        assert_eq!(it.next(), Some(1));
+        assert_eq!(it.len(), 2);
+        assert_eq!(it.rev().collect::<Vec<_>>(), vec![3, 2]);
+        let mut it = ("a",).into_tuple_iter();
+        assert_eq!(it.next(), Some("a"));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut a = (3, 4, 5);
+        assert_eq!(a.iter().max(), Some(&5));
+        for x in a.iter_mut() {
+            *x += 1;
+        }
+        assert_eq!(a, (4, 5, 6));
+        let mut b = ("a".to_owned(), "b".to_owned());
+        b.iter_mut().for_each(|s| s.push('!'));
+        assert_eq!(b.iter().map(|s| s.len()).sum::<usize>(), 4);
+    }
+
+    #[test]
+    fn test_array() {
+        fn norm2<V: fns::Tuple<Item = f64>>(v: V) -> f64 {
+            v.fold(0.0, |acc, x| acc + x * x)
+        }
+        assert_eq!(norm2((1.0, 2.0, 2.0)), 9.0);
+        assert_eq!(norm2([1.0, 2.0, 2.0]), 9.0);
+        let a = [1, 2, 3];
+        assert_eq!(fns::Tuple::zip_with(a, [4, 5, 6], |x, y| x * y), [4, 10, 18]);
+        assert_eq!(<[i32; 3] as fns::Tuple>::ARITY, 3);
+        let c: Option<[i32; 2]> = fns::Tuple::try_from_iter(vec![7, 8]);
+        assert_eq!(c, Some([7, 8]));
+        // The inherent methods of arrays and slices are not shadowed by the tuple traits.
+        let mut b = [1, 2, 3, 4];
+        b.rotate_left(1);
+        assert_eq!(b, [2, 3, 4, 1]);
+        assert_eq!(b.split_at(1), (&[2][..], &[3, 4, 1][..]));
+        assert_eq!(b.get(0..2), Some(&[2, 3][..]));
+        assert_eq!(b.iter().as_slice(), &[2, 3, 4, 1]);
+        assert_eq!([[1, 2], [3, 4]].concat(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_into_vec() {
+        assert_eq!((3, 3, 3).into_vec(), vec![3, 3, 3]);
+    }
+
+    #[test]
+    fn test_join() {
+        assert_eq!(("a", "b", "c").join(", "), "a, b, c");
+        assert_eq!(("a".to_owned(),).join(", "), "a");
+    }
+
+    #[test]
+    fn test_into_dyn_vec() {
+        use std::fmt::Display;
+        let v = (3, 4, 5).into_dyn_vec(|x| Box::new(x) as Box<dyn Display>);
+        let s: Vec<_> = v.iter().map(|x| x.to_string()).collect();
+        assert_eq!(s, vec!["3", "4", "5"]);
+    }
+
+    #[test]
+    fn test_lock_all() {
+        use std::sync::{Mutex, RwLock};
+        let a = (Mutex::new(3), Mutex::new(4));
+        {
+            let (mut x, y) = a.lock_all();
+            *x += *y;
+        }
+        assert_eq!(*a.0.lock().unwrap(), 7);
+        let b = (RwLock::new(1), RwLock::new(2));
+        b.lock_all().map(|mut x| *x *= 2);
+        assert_eq!(*b.1.read().unwrap(), 4);
+    }
+
+    #[test]
+    fn test_insert_at() {
+        let a = (1, 3);
+        assert_eq!(a.insert_at::<0>(0), (0, 1, 3));
+        assert_eq!(a.insert_at::<1>(2), (1, 2, 3));
+        assert_eq!(a.insert_at::<2>(4), (1, 3, 4));
+    }
+
+    #[test]
+    fn test_inspect() {
+        let a = (3, 4, 5);
+        let mut v = vec![];
+        let b = a.inspect(|&x| v.push(x)).map(|x| x * 2);
+        assert_eq!(b, (6, 8, 10));
+        assert_eq!(v, vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn test_iota() {
+        let a: (i32, i32, i32) = TupleMap3::iota(1, 2);
+        assert_eq!(a, (1, 3, 5));
+        let b: (u8, u8) = TupleMap2::iota(254, 1);
+        assert_eq!(b, (254, 255));
+        let c: (f64,) = TupleMap1::iota(0.5, 1.0);
+        assert_eq!(c, (0.5,));
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(<(i32, i32, i32) as TupleMap3>::ARITY, 3);
+        assert_eq!(<(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8) as TupleMap16>::ARITY, 16);
+        assert_eq!((3, 4, 5).len(), 3);
+        assert_eq!(("a",).len(), 1);
+        assert!(!(3, 4).is_empty());
+        fn buffer<T: TupleMap4>() -> Vec<T::Item> {
+            Vec::with_capacity(T::ARITY)
+        }
+        assert!(buffer::<(i32, i32, i32, i32)>().capacity() >= 4);
+    }
+
+    #[test]
+    fn test_map() {
+        let a = (3, 3, 3);
+        let mut cnt = 0;
+        let b = a.map(|x| {
+            cnt += 1;
+            x + cnt
+        });
+        assert_eq!(b, (4, 5, 6))
+    }
+
+    #[test]
+    fn test_map_box() {
+        assert_eq!((3, 4).map_box(), (Box::new(3), Box::new(4)));
+    }
+
+    #[test]
+    fn test_map_as_ref() {
+        use std::path::{Path, PathBuf};
+        let a = (String::from("a"), String::from("b"));
+        assert_eq!(a.map_as_ref::<str>(), ("a", "b"));
+        let p = (PathBuf::from("/tmp"), PathBuf::from("/usr"));
+        assert_eq!(p.map_as_ref(), (Path::new("/tmp"), Path::new("/usr")));
+    }
+
+    #[test]
+    fn test_map_err_each() {
+        let a: (Result<i32, i32>, _, _) = (Ok(3), Err(4), Ok(5));
+        assert_eq!(a.map_err_each(|e| e.to_string()), (Ok(3), Err("4".to_owned()), Ok(5)));
+    }
+
+    #[test]
+    fn test_map_ok_each() {
+        let a: (Result<i32, &str>, _, _) = (Ok(3), Err("bad"), Ok(5));
+        assert_eq!(a.map_ok_each(|x| x + 1), (Ok(4), Err("bad"), Ok(6)));
+    }
+
+    #[test]
+    fn test_map_inner() {
+        let a = (Some(3), None, Some(5));
+        assert_eq!(a.map_inner(|x| x * 2), (Some(6), None, Some(10)));
+    }
+
+    #[test]
+    fn test_map_to_owned() {
+        let a = ("a", "b", "c");
+        let b: (String, String, String) = a.map_to_owned();
+        assert_eq!(b, ("a", "b", "c").map(String::from));
+        let v = [1, 2, 3];
+        assert_eq!((&v[..1], &v[1..]).map_to_owned(), (vec![1], vec![2, 3]));
+    }
+
+    #[test]
+    fn test_map_at() {
+        use arity::MapAt;
+        assert_eq!(MapAt::<1>::map_at((3, 4, 5), |x| x * 10), (3, 40, 5));
+        assert_eq!(MapAt::<2>::map_at((3, 4, 5), |x| x.to_string()), (3, 4, "5".to_owned()));
+        assert_eq!(MapAt::<0>::map_at((3,), |x| x > 0), (true,));
+        let a = MapAt::<0>::map_at((3, "ab", 'c'), |x| x as f64);
+        let b = MapAt::<1>::map_at(a, str::len);
+        assert_eq!(MapAt::<2>::map_at(b, char::is_alphabetic), (3.0, 2, true));
+    }
+
+    #[test]
+    fn test_map_first_last() {
+        assert_eq!((3, 4, 5).map_first(|x| x * 10), (30, 4, 5));
+        assert_eq!((3, 4, 5).map_last(|x| x * 10), (3, 4, 50));
+        assert_eq!((3,).map_last(|x| x + 1), (4,));
+        let a = ("a".to_owned(), "b".to_owned()).map_last(|s| s + "!");
+        assert_eq!(a, ("a".to_owned(), "b!".to_owned()));
+    }
+
+    #[test]
+    fn test_map_nth() {
+        assert_eq!((3, 4, 5).map_nth(1, |x| x * 10), (3, 40, 5));
+        assert_eq!((3, 4, 5).map_nth(3, |x| x * 10), (3, 4, 5));
+        let a = ("a".to_owned(), "b".to_owned()).map_nth(0, |s| s + "!");
+        assert_eq!(a, ("a!".to_owned(), "b".to_owned()));
+    }
+
+    #[test]
+    fn test_map_with_index() {
+        let a = (3, 4, 5);
+        assert_eq!(a.map_with_index(|i, x| i * x), (0, 4, 10));
+    }
+
+    #[test]
+    fn test_nth() {
+        let a = (3, 4, 5, 6);
+        assert_eq!(a.nth(2), Some(5));
+    }
+
+    #[test]
+    fn test_nth_const() {
+        let a = (3, 4, 5);
+        assert_eq!(a.nth_const::<0>(), 3);
+        assert_eq!(a.nth_const::<2>(), 5);
+        let b = ("a".to_owned(), "b".to_owned(), "c".to_owned());
+        assert_eq!(b.nth_const::<1>(), "b");
+    }
+
+    #[test]
+    fn test_partition_result() {
+        let a: (Result<i32, &str>, _, _, _) = (Ok(3), Err("x"), Ok(5), Err("y"));
+        assert_eq!(a.partition_result(), (vec![3, 5], vec!["x", "y"]));
+    }
+
+    #[test]
+    fn test_pairwise() {
+        use std::rc::Rc;
+        let a = (1, 3, 6, 10);
+        assert_eq!(a.pairwise(), ((1, 3), (3, 6), (6, 10)));
+        assert_eq!(a.pairwise().map(|(x, y)| y - x), (2, 3, 4));
+        assert_eq!((1,).pairwise(), ());
+        let b = (Rc::new(1), Rc::new(2), Rc::new(3));
+        let ((x, y), (z, w)) = b.pairwise();
+        assert_eq!((Rc::strong_count(&x), Rc::strong_count(&y)), (1, 2));
+        assert_eq!((Rc::strong_count(&z), Rc::strong_count(&w)), (2, 1));
+        assert!(Rc::ptr_eq(&y, &z));
+    }
+
+    #[test]
+    fn test_partition() {
+        let a = (3, 4, 5, 6);
+        let (even, odd) = a.partition(|&x| x % 2 == 0);
+        assert_eq!(even, vec![4, 6]);
+        assert_eq!(odd, vec![3, 5]);
+    }
+
+    #[cfg(feature = "either")]
+    #[test]
+    fn test_partition_map() {
+        use either::Either;
+        let a = ("3", "x", "5");
+        let (nums, errs): (Vec<i32>, Vec<&str>) = a.partition_map(|s| match s.parse() {
+            Ok(x) => Either::Left(x),
+            Err(_) => Either::Right(s),
+        });
+        assert_eq!(nums, vec![3, 5]);
+        assert_eq!(errs, vec!["x"]);
+    }
+
+    #[test]
+    fn test_pop() {
+        let a = (1, 2, 3);
+        assert_eq!(a.pop_back(), ((1, 2), 3));
+        assert_eq!(a.pop_front(), (1, (2, 3)));
+        assert_eq!(a.push_back(4).pop_back(), (a, 4));
+    }
+
+    #[test]
+    fn test_permutations() {
+        assert_eq!(
+            (1, 2, 3).permutations().collect::<Vec<_>>(),
+            vec![(1, 2, 3), (1, 3, 2), (2, 1, 3), (2, 3, 1), (3, 1, 2), (3, 2, 1)]
+        );
+        assert_eq!((1,).permutations().collect::<Vec<_>>(), vec![(1,)]);
+        assert_eq!((1, 1).permutations().collect::<Vec<_>>(), vec![(1, 1), (1, 1)]);
+        assert_eq!((1, 2, 3, 4, 5, 6).permutations().count(), 720);
+        let mut a = ("a".to_owned(), "b".to_owned()).permutations();
+        a.next();
+        assert_eq!(a.next(), Some(("b".to_owned(), "a".to_owned())));
+        assert_eq!(a.next(), None);
+        let b = <(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8)>::iota(0, 1);
+        let c = b.permutations().nth(1).unwrap();
+        assert_eq!((c.13, c.14, c.15), (13, 15, 14));
+    }
+
+    #[test]
+    fn test_poly_map() {
+        use poly::{Func, PolyMap};
+        struct Size;
+        impl<T> Func<T> for Size {
+            type Output = usize;
+            fn call(&mut self, _: T) -> usize {
+                ::std::mem::size_of::<T>()
+            }
+        }
+        assert_eq!((1u8, 2u32, 3.0f64).poly_map(Size), (1, 4, 8));
+        struct Counter(usize);
+        impl Func<&str> for Counter {
+            type Output = (usize, String);
+            fn call(&mut self, x: &str) -> (usize, String) {
+                self.0 += 1;
+                (self.0, x.to_owned())
+            }
+        }
+        impl Func<i32> for Counter {
+            type Output = (usize, i32);
+            fn call(&mut self, x: i32) -> (usize, i32) {
+                self.0 += 1;
+                (self.0, x * 2)
+            }
+        }
+        assert_eq!(
+            ("a", 3, "b").poly_map(Counter(0)),
+            ((1, "a".to_owned()), (2, 6), (3, "b".to_owned()))
+        );
+    }
 
     #[test]
-    fn test_all() {
-        let a = (3, 9, 12, 15);
-        assert!(a.all(|x| x % 3 == 0));
-        assert!(!a.all(|x| x % 4 == 0));
+    fn test_position() {
+        let a = (3, 4, 5, 6);
+        assert_eq!(a.position(|&x| x % 2 == 0), Some(1));
+        assert_eq!(a.position(|&x| x > 6), None);
     }
 
     #[test]
-    fn test_any() {
-        let a = (3, 9, 12, 15);
-        assert!(a.any(|x| x % 4 == 0));
-        assert!(!a.any(|x| x % 7 == 0));
+    fn test_push() {
+        let a = (1, 2);
+        assert_eq!(a.push_back(3), (1, 2, 3));
+        assert_eq!(a.push_front(0), (0, 1, 2));
+        assert_eq!(a.push_back(3).push_front(0).push_back(4), (0, 1, 2, 3, 4));
     }
 
     #[test]
-    fn test_by_ref() {
-        let a = (3, 3, 3);
-        let b = a.by_ref();
-        assert_eq!(b, (&3, &3, &3))
+    fn test_reduce() {
+        let a = (3, 8, 5);
+        assert_eq!(a.reduce(|x, y| if x > y { x } else { y }), 8);
+        assert_eq!((3,).reduce(|x, y| x + y), 3);
     }
 
     #[test]
-    fn test_by_ref_mut() {
-        let mut a = (3, 3, 3);
-        assert_eq!(a.by_ref_mut(), (&mut 3, &mut 3, &mut 3));
-        a.by_ref_mut().for_each(|x| *x += 5);
-        assert_eq!(a, (8, 8, 8))
+    fn test_remove_at() {
+        let a = (1, 2, 3);
+        assert_eq!(a.remove_at::<0>(), (1, (2, 3)));
+        assert_eq!(a.remove_at::<1>(), (2, (1, 3)));
+        assert_eq!(a.remove_at::<2>(), (3, (1, 2)));
+        assert_eq!((1,).remove_at::<0>(), (1, ()));
+        assert_eq!(a.insert_at::<1>(5).remove_at::<1>(), (5, a));
     }
 
     #[test]
-    fn test_cloned() {
-        let mut a = (3, 3, 3);
-        assert_eq!(a, a.cloned());
-        let b = a.cloned().map(|x| x * 3);
-        a.by_ref_mut().for_each(|x| *x *= 3);
-        assert_eq!(b, a.cloned())
+    fn test_rev() {
+        let a = (3, 4, 5, 6);
+        assert_eq!(a.rev(), (6, 5, 4, 3));
+        assert_eq!((1,).rev(), (1,));
     }
 
     #[test]
-    fn test_find() {
-        let mut a = (3, 3, 5, 3);
-        if let Some(x) = a.by_ref_mut().find(|&&mut x| x == 5) {
-            *x = 3
-        }
-        assert!(a.same());
+    fn test_rfind() {
+        let a = (3, 4, 5, 6, 7);
+        assert_eq!(a.rfind(|&x| x % 2 == 0), Some(6));
+        assert_eq!(a.rfind(|&x| x > 7), None);
     }
 
     #[test]
-    fn test_fold() {
-        let a = (3, 3, 3, 3);
-        let sum = a.fold(0, |sum, x| sum + x);
-        assert_eq!(sum, 12)
+    fn test_rfold() {
+        let a = ("a", "b", "c");
+        assert_eq!(a.rfold(String::new(), |s, x| s + x), "cba");
     }
 
     #[test]
-    fn test_into_vec() {
-        assert_eq!((3, 3, 3).into_vec(), vec![3, 3, 3]);
+    fn test_rotate() {
+        let a = (1, 2, 3);
+        assert_eq!(a.rotate_left::<1>(), (2, 3, 1));
+        assert_eq!(a.rotate_right::<1>(), (3, 1, 2));
+        assert_eq!(a.rotate_left::<3>(), a);
+        assert_eq!(a.rotate_right::<4>(), (3, 1, 2));
     }
 
     #[test]
-    fn test_map() {
-        let a = (3, 3, 3);
-        let mut cnt = 0;
-        let b = a.map(|x| {
-            cnt += 1;
-            x + cnt
-        });
-        assert_eq!(b, (4, 5, 6))
+    fn test_rotate_mut() {
+        let mut a = (1, 2, 3, 4, 5);
+        a.rotate_left_mut(2);
+        assert_eq!(a, (3, 4, 5, 1, 2));
+        a.rotate_right_mut(7);
+        assert_eq!(a, (1, 2, 3, 4, 5));
+        let mut b = ("a".to_owned(), "b".to_owned(), "c".to_owned());
+        b.rotate_right_mut(1);
+        assert_eq!(b, ("c".to_owned(), "a".to_owned(), "b".to_owned()));
     }
 
     #[test]
-    fn test_nth() {
-        let a = (3, 4, 5, 6);
-        assert_eq!(a.nth(2), Some(5));
+    fn test_reverse_mut() {
+        let mut a = (1, 2, 3, 4, 5);
+        a.reverse_mut();
+        assert_eq!(a, (5, 4, 3, 2, 1));
+        let mut b = ("a".to_owned(), "b".to_owned());
+        b.reverse_mut();
+        assert_eq!(b, ("b".to_owned(), "a".to_owned()));
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut a = (1, 2, 3, 4);
+        a.swap(0, 3);
+        assert_eq!(a, (4, 2, 3, 1));
+        a.swap(2, 1);
+        assert_eq!(a, (4, 3, 2, 1));
+        a.swap(1, 1);
+        assert_eq!(a, (4, 3, 2, 1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_swap_out_of_bounds() {
+        let mut a = (1, 2, 3);
+        a.swap(0, 3);
+    }
+
+    #[test]
+    fn test_swap_with() {
+        let mut a = (1, 2, 3);
+        let mut b = (4, 5, 6);
+        a.swap_with(&mut b);
+        assert_eq!(a, (4, 5, 6));
+        assert_eq!(b, (1, 2, 3));
+        let mut c = ("a".to_owned(), "b".to_owned());
+        let mut d = ("c".to_owned(), "d".to_owned());
+        c.swap_with(&mut d);
+        assert_eq!(c, ("c".to_owned(), "d".to_owned()));
+        assert_eq!(d, ("a".to_owned(), "b".to_owned()));
+    }
+
+    #[test]
+    fn test_rposition() {
+        let a = (3, 4, 5, 6, 7);
+        assert_eq!(a.rposition(|&x| x % 2 == 0), Some(3));
+        let mut checked = vec![];
+        a.rposition(|&x| {
+            checked.push(x);
+            false
+        });
+        assert_eq!(checked, vec![7, 6, 5, 4, 3]);
     }
 
     #[test]
@@ -727,6 +3985,189 @@ mod tests {
         assert!(a.same_as(3));
     }
 
+    #[test]
+    fn test_scan() {
+        let a = (3, 4, 5);
+        let b = a.scan(0, |sum, x| {
+            *sum += x;
+            *sum
+        });
+        assert_eq!(b, (3, 7, 12));
+    }
+
+    #[test]
+    fn test_each_array() {
+        let mut a = (3, 1, 2);
+        assert_eq!(a.each_ref_array().iter().map(|x| **x).max(), Some(3));
+        for x in a.each_mut_array() {
+            *x *= 2;
+        }
+        assert_eq!(a, (6, 2, 4));
+        let mut b = ("a".to_owned(), "b".to_owned());
+        b.each_mut_array()[1].push('c');
+        assert_eq!(b.each_ref_array(), [&"a".to_owned(), &"bc".to_owned()]);
+    }
+
+    #[test]
+    fn test_as_shared() {
+        let mut a = (3, 4, 5);
+        let b = a.by_ref_mut();
+        assert_eq!(b.as_shared(), (&3, &4, &5));
+    }
+
+    #[test]
+    fn test_reborrow() {
+        let mut a = (3, 4, 5);
+        {
+            let mut b = a.by_ref_mut();
+            b.reborrow().for_each(|x| *x += 1);
+            b.for_each(|x| *x *= 2);
+        }
+        assert_eq!(a, (8, 10, 12));
+    }
+
+    #[test]
+    fn test_try_lock_all() {
+        use std::sync::Mutex;
+        let a = (Mutex::new(3), Mutex::new(4), Mutex::new(5));
+        {
+            let _guard = a.2.lock().unwrap();
+            assert!(a.try_lock_all().is_none());
+            assert!(a.0.try_lock().is_ok());
+        }
+        assert!(a.try_lock_all().is_some());
+    }
+
+    #[test]
+    fn test_try_fold() {
+        let a = (3u8, 200, 100);
+        assert_eq!(a.try_fold(0u8, |sum, x| sum.checked_add(x).ok_or(x)), Err(100));
+        let b = (3u8, 4, 5);
+        assert_eq!(b.try_fold(0u8, |sum, x| sum.checked_add(x).ok_or(x)), Ok(12));
+    }
+
+    #[test]
+    fn test_try_for_each() {
+        let a = ("1", "x", "3");
+        let mut v = vec![];
+        assert!(a.try_for_each(|s| s.parse::<i32>().map(|x| v.push(x))).is_err());
+        assert_eq!(v, vec![1]);
+    }
+
+    #[test]
+    fn test_try_from_iter() {
+        let a: Option<(i32, i32, i32)> = TupleMap3::try_from_iter(vec![1, 2, 3]);
+        assert_eq!(a, Some((1, 2, 3)));
+        let b: Option<(i32, i32, i32)> = TupleMap3::try_from_iter(vec![1, 2]);
+        assert_eq!(b, None);
+        let c: Option<(i32, i32)> = TupleMap2::try_from_iter(vec![1, 2, 3]);
+        assert_eq!(c, None);
+    }
+
+    #[test]
+    fn test_collect_tuple() {
+        use iter::CollectTuple;
+        let a: Option<(usize, usize, usize)> = "abc".chars().map(|c| c.len_utf8()).collect_tuple();
+        assert_eq!(a, Some((1, 1, 1)));
+        assert_eq!((0..5).collect_tuple::<(i32, i32)>(), None);
+        assert_eq!(
+            "a,b".split(',').map(str::to_owned).collect_tuple(),
+            Some(("a".to_owned(), "b".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_try_from_vec() {
+        use error::LenError;
+        let a: Result<(i32, i32, i32), _> = TupleMap3::try_from_vec(vec![1, 2, 3]);
+        assert_eq!(a, Ok((1, 2, 3)));
+        let b: Result<(i32, i32, i32), _> = TupleMap3::try_from_vec(vec![1, 2]);
+        assert_eq!(b, Err(LenError { expected: 3, actual: 2 }));
+        let row: Vec<String> = "x,y,z,w".split(',').map(str::to_owned).collect();
+        let c = <(String, String, String)>::try_from_vec(row);
+        assert_eq!(c, Err(LenError { expected: 3, actual: 4 }));
+        assert_eq!(
+            c.unwrap_err().to_string(),
+            "expected a sequence of length 3, but got length 4"
+        );
+    }
+
+    #[test]
+    fn test_try_from_slice() {
+        use error::LenError;
+        let a: Result<(i32, i32, i32), _> = TupleMap3::try_from_slice(&[1, 2, 3]);
+        assert_eq!(a, Ok((1, 2, 3)));
+        let b: Result<(i32, i32, i32), _> = TupleMap3::try_from_slice(&[1, 2, 3, 4]);
+        assert_eq!(b, Err(LenError { expected: 3, actual: 4 }));
+        let v = vec!["a".to_owned(), "b".to_owned()];
+        assert_eq!(
+            <(String, String)>::try_from_slice(&v),
+            Ok(("a".to_owned(), "b".to_owned()))
+        );
+        assert_eq!(
+            <(String,)>::try_from_slice(&v[..0]),
+            Err(LenError { expected: 1, actual: 0 })
+        );
+    }
+
+    #[test]
+    fn test_try_zipf() {
+        let a = (6i32, 8, 10);
+        assert_eq!(a.try_zipf((3, 4, 5), |x, y| x.checked_div(y).ok_or(y)), Ok((2, 2, 2)));
+        let mut cnt = 0;
+        let res = a.try_zipf((3, 0, 0), |x, y| {
+            cnt += 1;
+            x.checked_div(y).ok_or(cnt)
+        });
+        assert_eq!(res, Err(2));
+    }
+
+    #[test]
+    fn test_unwrap_all() {
+        let a: (Result<i32, &str>, _, _) = (Ok(3), Ok(4), Ok(5));
+        assert_eq!(a.unwrap_all(), (3, 4, 5));
+    }
+
+    #[test]
+    #[should_panic(expected = "called `unwrap_all()` on an `Err` value at index 2: \"bad\"")]
+    fn test_unwrap_all_err() {
+        let a: (Result<i32, &str>, _, _) = (Ok(3), Ok(4), Err("bad"));
+        a.unwrap_all();
+    }
+
+    #[test]
+    fn test_unwrap_or_all() {
+        let a = (Some(3), None, Some(5));
+        assert_eq!(a.unwrap_or_all(0), (3, 0, 5));
+    }
+
+    #[test]
+    fn test_unwrap_or_else_each() {
+        let a: (Result<i32, &str>, _, _) = (Ok(3), Err("bad"), Ok(5));
+        assert_eq!(a.unwrap_or_else_each(|e| e.len() as i32), (3, 3, 5));
+    }
+
+    #[test]
+    fn test_uncons() {
+        let a = (1, 2, 3);
+        assert_eq!(a.uncons(), (1, (2, 3)));
+        assert_eq!((1,).uncons(), (1, ()));
+    }
+
+    #[test]
+    fn test_unsnoc() {
+        let a = (1, 2, 3);
+        assert_eq!(a.unsnoc(), ((1, 2), 3));
+        assert_eq!((1,).unsnoc(), ((), 1));
+    }
+
+    #[test]
+    fn test_unzip() {
+        let a = (1, 2, 3);
+        let b = ("a", "b", "c");
+        assert_eq!(a.zip(b).unzip(), (a, b));
+    }
+
     #[test]
     fn test_zip() {
         let a = (1, 2, 3);
@@ -734,6 +4175,15 @@ mod tests {
         assert_eq!(a.zip(b), ((1, "a"), (2, "b"), (3, "c")));
     }
 
+    #[test]
+    fn test_zip_pad() {
+        let a = (1, 2, 3, 4);
+        let b = ("a", "b", "c");
+        assert_eq!(a.zip_pad(b, "z"), ((1, "a"), (2, "b"), (3, "c"), (4, "z")));
+        let c = (Some(1), Some(2)).zip_pad((5,).map(Some), None);
+        assert_eq!(c, ((Some(1), Some(5)), (Some(2), None)));
+    }
+
     #[test]
     fn test_zipf() {
         let a = (1, 2, 3);
@@ -744,6 +4194,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_zip3() {
+        let a = (3, 4, 5);
+        let b = ('a', 'b', 'c');
+        let c = (true, false, true);
+        assert_eq!(a.zip3(b, c), ((3, 'a', true), (4, 'b', false), (5, 'c', true)));
+    }
+
+    #[test]
+    fn test_zipf3() {
+        let a = (3, 4, 5);
+        assert_eq!(a.zipf3((1, 2, 3), (2, 2, 2), |x, y, z| (x + y) * z), (8, 12, 16));
+    }
+
     #[test]
     fn test_add() {
         let a = (3, 4, 5);
@@ -769,6 +4233,194 @@ mod tests {
         assert!(a.div(b).same_as(2));
     }
 
+    #[test]
+    fn test_elem_ops() {
+        let a = (6, 8, 10);
+        let b = (3, 4, 5);
+        assert_eq!(a.elem_add(b), (9, 12, 15));
+        assert_eq!(a.elem_sub(b), (3, 4, 5));
+        assert_eq!(a.elem_mul(b), (18, 32, 50));
+        assert_eq!(a.elem_div(b), (2, 2, 2));
+    }
+
+    #[test]
+    fn test_assign_ops() {
+        let mut a = (6, 8, 10);
+        a.add_assign((3, 4, 5));
+        assert_eq!(a, (9, 12, 15));
+        a.sub_assign((3, 4, 5));
+        assert_eq!(a, (6, 8, 10));
+        a.mul_assign((3, 4, 5));
+        assert_eq!(a, (18, 32, 50));
+        a.div_assign((3, 4, 5));
+        assert_eq!(a, (6, 8, 10));
+        let mut s = ("a".to_owned(), "b".to_owned());
+        s.add_assign(("x", "y"));
+        assert_eq!(s, ("ax".to_owned(), "by".to_owned()));
+    }
+
+    #[test]
+    fn test_linspace() {
+        let a: (f64, f64, f64, f64, f64) = TupleArith5::linspace(0.0, 1.0);
+        assert_eq!(a, (0.0, 0.25, 0.5, 0.75, 1.0));
+        let b: (f32, f32, f32) = TupleArith3::linspace(1.0, -1.0);
+        assert_eq!(b, (1.0, 0.0, -1.0));
+        let c: (f64,) = TupleArith1::linspace(2.0, 3.0);
+        assert_eq!(c, (2.0,));
+        let d: (f64, f64, f64, f64) = TupleArith4::linspace(0.1, 0.7);
+        assert_eq!(d.3, 0.7);
+    }
+
+    #[test]
+    fn test_midpoint() {
+        let a = (0u8, 10, 255);
+        let b = (255u8, 20, 255);
+        assert_eq!(a.midpoint(b), (127, 15, 255));
+        assert_eq!((1.0, -2.0).midpoint((2.0, 3.0)), (1.5, 0.5));
+        assert_eq!((f64::MAX, 1.0).midpoint((f64::MAX, 2.0)), (f64::MAX, 1.5));
+    }
+
+    #[test]
+    fn test_abs_diff() {
+        let a = (3u8, 200, 7);
+        let b = (5u8, 100, 7);
+        assert_eq!(a.abs_diff(b), (2, 100, 0));
+        assert_eq!((-128i8, 127).abs_diff((127, -128)), (255u8, 255u8));
+        assert_eq!((1.5, -2.0).abs_diff((2.0, 3.0)), (0.5, 5.0));
+    }
+
+    #[test]
+    fn test_spawn_map() {
+        let a = (3, 4, 5);
+        assert_eq!(a.spawn_map(|x| x * 2), (6, 8, 10));
+        let ids = ("a", "b").spawn_map(|_| ::std::thread::current().id());
+        assert_ne!(ids.0, ids.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "boom")]
+    fn test_spawn_map_panic() {
+        (1, 2).spawn_map(|x| if x == 2 { panic!("boom") } else { x });
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn test_arbitrary_tuple_shrink() {
+        use arbitrary::ArbitraryTuple;
+        use quickcheck::Arbitrary;
+        let a = ArbitraryTuple((2u8, 0, 1));
+        let shrunk: Vec<_> = a.shrink().collect();
+        assert!(!shrunk.is_empty());
+        assert!(shrunk.iter().all(|b| b.0.zip(a.0).all(|(x, y)| x <= y)));
+        assert!(shrunk.iter().all(|b| b.0 .1 == 0));
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn test_to_glam() {
+        use vector::{ToVec2, ToVec3, ToVec4};
+        assert_eq!((1.0f32, 2.0).to_vec2(), glam::Vec2::new(1.0, 2.0));
+        assert_eq!((1.0f64, 2.0, 3.0).to_vec3(), glam::DVec3::new(1.0, 2.0, 3.0));
+        let v = (1.0f32, 2.0, 3.0, 4.0).to_vec4();
+        let t: (f32, f32, f32, f32) = v.into();
+        assert_eq!(t, (1.0, 2.0, 3.0, 4.0));
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_to_vector() {
+        use vector::{FromVector, ToVector};
+        let v = (1.0, 2.0, 3.0, 4.0, 5.0).to_vector();
+        assert_eq!(v, nalgebra::SVector::from([1.0, 2.0, 3.0, 4.0, 5.0]));
+        assert_eq!(<(f64, f64, f64, f64, f64)>::from_vector(v * 2.0), (2.0, 4.0, 6.0, 8.0, 10.0));
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_to_array() {
+        use ndim::{ToArray1, ToArray2};
+        assert_eq!((1.0, 2.0).to_array1(), ndarray::arr1(&[1.0, 2.0]));
+        let a = ((1, 2, 3), (4, 5, 6)).to_array2();
+        assert_eq!(a.shape(), &[2, 3]);
+        assert_eq!(a[[1, 0]], 4);
+    }
+
+    #[test]
+    fn test_select() {
+        let a = (1, 2, 3);
+        assert_eq!(a.select2::<2, 0>(), (3, 1));
+        assert_eq!(a.select3::<2, 1, 0>(), (3, 2, 1));
+        assert_eq!(a.select4::<0, 0, 1, 1>(), (1, 1, 2, 2));
+        let b = ("x".to_owned(), "y".to_owned());
+        assert_eq!(b.select3::<1, 1, 0>(), ("y".to_owned(), "y".to_owned(), "x".to_owned()));
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn test_select_first() {
+        use futures::executor::block_on;
+        use futures::future::{self, Either, FusedFuture};
+        let a = (
+            Either::Left(future::pending()),
+            Either::Right(future::ready(4)),
+            Either::Right(future::ready(5)),
+        );
+        let mut s = a.select_first();
+        assert_eq!(block_on(&mut s), (4, 1));
+        assert!(s.is_terminated());
+    }
+
+    #[test]
+    fn test_splat() {
+        let a: (i32, i32, i32) = TupleMap3::splat(1);
+        assert_eq!(a, (1, 1, 1));
+        let b = <(String,)>::splat("a".to_owned());
+        assert_eq!(b, ("a".to_owned(),));
+    }
+
+    #[test]
+    fn test_split() {
+        let a = (1, 0, 2, 3, 0);
+        assert_eq!(a.split(|&x| x == 0), vec![vec![1], vec![2, 3], vec![]]);
+        assert_eq!((1, 2).split(|&x| x == 0), vec![vec![1, 2]]);
+    }
+
+    #[test]
+    fn test_split_at() {
+        let a = (1, 2, 3, 4, 5);
+        assert_eq!(a.split_at::<2>(), ((1, 2), (3, 4, 5)));
+        assert_eq!(a.split_at::<0>(), ((), a));
+        assert_eq!(a.split_at::<5>(), (a, ()));
+    }
+
+    #[test]
+    fn test_starts_with() {
+        let a = (3, 4, 5, 6);
+        assert!(a.starts_with(&(3, 4)));
+        assert!(a.starts_with(&(3, 4, 5, 6)));
+        assert!(a.starts_with(&[3, 4]));
+        assert!(a.starts_with(&[3, 4][..]));
+        assert!(!a.starts_with(&(4,)));
+        assert!(!a.starts_with(&[3, 4, 5, 6, 7][..]));
+    }
+
+    #[test]
+    fn test_default_tuple() {
+        let a: (f64, f64, f64) = TupleMap3::default_tuple();
+        assert_eq!(a, (0.0, 0.0, 0.0));
+        let b: (String, String) = TupleMap2::default_tuple();
+        assert_eq!(b, (String::new(), String::new()));
+    }
+
+    #[test]
+    fn test_ends_with() {
+        let a = (3, 4, 5, 6);
+        assert!(a.ends_with(&(5, 6)));
+        assert!(a.ends_with(&[4, 5, 6]));
+        assert!(!a.ends_with(&(5,)));
+        assert!(!a.ends_with(&[2, 3, 4, 5, 6][..]));
+    }
+
     #[test]
     fn test_sum() {
         let a = (6, 8, 10);
@@ -781,6 +4433,73 @@ mod tests {
         assert_eq!(a.product(), 480);
     }
 
+    #[test]
+    fn test_widen_narrow() {
+        let a = (1.0, 2.0);
+        let b = a.widen::<3>();
+        assert_eq!(b, (1.0, 2.0, 0.0));
+        assert_eq!(b.narrow::<2>(), a);
+        assert_eq!(a.widen::<2>(), a);
+        assert_eq!((1, 2, 3).narrow::<1>(), (1,));
+        assert_eq!(("a".to_owned(),).widen::<2>(), ("a".to_owned(), String::new()));
+    }
+
+    #[test]
+    fn test_take_skip() {
+        let a = (1, 2, 3, 4);
+        assert_eq!(a.take::<2>(), (1, 2));
+        assert_eq!(a.skip::<2>(), (3, 4));
+        assert_eq!(a.take::<0>(), ());
+        assert_eq!(a.skip::<4>(), ());
+        assert_eq!(a.take::<4>(), a);
+    }
+
+    #[test]
+    fn test_sorted_total() {
+        let a = (2.0, f64::NAN, 0.0, -0.0, -1.0);
+        let (b, c, d, e, f) = a.sorted_total();
+        assert_eq!((b, c, d, e), (-1.0, -0.0, 0.0, 2.0));
+        assert!(c.is_sign_negative() && d.is_sign_positive());
+        assert!(f.is_nan());
+    }
+
+    #[test]
+    fn test_chunks2() {
+        let a = (1, 2, 3, 4, 5, 6);
+        assert_eq!(a.chunks2(), ((1, 2), (3, 4), (5, 6)));
+    }
+
+    #[test]
+    fn test_fold_chunks2() {
+        let a = (1, 2, 3, 4, 5, 6);
+        let v = a.fold_chunks2(vec![], |mut v, x, y| {
+            v.push(x * y);
+            v
+        });
+        assert_eq!(v, vec![2, 12, 30]);
+    }
+
+    #[test]
+    fn test_swap_halves() {
+        let a = (1, 2, 3, 4, 5, 6);
+        assert_eq!(a.swap_halves(), (4, 5, 6, 1, 2, 3));
+    }
+
+    #[test]
+    fn test_summary() {
+        let s = (6, 8, 10, 4).summary();
+        assert_eq!(
+            s,
+            TupleSummary {
+                min: 4,
+                max: 10,
+                sum: 28,
+                mean: 7.0,
+                count: 4,
+            }
+        );
+    }
+
     #[test]
     fn test_tmin() {
         let a = (6, 8, 10);
@@ -0,0 +1,58 @@
+//! Conversions from tuples into [ndarray](https://docs.rs/ndarray) arrays, enabled by
+//! `ndarray` feature.
+//! # Example
+//! ```
+//! # extern crate ndarray;
+//! # extern crate tuple_map;
+//! use ndarray::{arr1, arr2};
+//! use tuple_map::ndim::{ToArray1, ToArray2};
+//! # fn main() {
+//! assert_eq!((1, 2, 3).to_array1(), arr1(&[1, 2, 3]));
+//! let kernel = ((0, 1, 0), (1, -4, 1), (0, 1, 0));
+//! assert_eq!(kernel.to_array2(), arr2(&[[0, 1, 0], [1, -4, 1], [0, 1, 0]]));
+//! # }
+//! ```
+use fns::{self, Tuple};
+use ndarray::{Array1, Array2};
+
+/// Tuples which can be converted into a 1D array.
+pub trait ToArray1: Tuple {
+    /// Converts `(a, a, a, ...)` into `Array1`.
+    fn to_array1(self) -> Array1<Self::Item>;
+}
+
+impl<U: Tuple> ToArray1 for U {
+    fn to_array1(self) -> Array1<Self::Item> {
+        Array1::from(fns::into_vec(self))
+    }
+}
+
+/// Tuples of tuples which can be converted into a 2D array.
+pub trait ToArray2 {
+    /// The type of elements.
+    type Elem;
+    /// Converts `((a, a, ...), (a, a, ...), ...)` into `Array2`, where each inner tuple
+    /// becomes a row.
+    fn to_array2(self) -> Array2<Self::Elem>;
+}
+
+impl<U> ToArray2 for U
+where
+    U: Tuple,
+    U::Item: Tuple,
+{
+    type Elem = <U::Item as Tuple>::Item;
+    fn to_array2(self) -> Array2<Self::Elem> {
+        let mut rows = 0;
+        let elems = self.fold(Vec::new(), |mut v, row| {
+            rows += 1;
+            row.fold(&mut v, |v, x| {
+                v.push(x);
+                v
+            });
+            v
+        });
+        let cols = elems.len() / rows;
+        Array2::from_shape_vec((rows, cols), elems).expect("all rows have the same length")
+    }
+}
@@ -0,0 +1,130 @@
+//! Helper traits for numeric elements, used by element-wise methods like
+//! [`TupleArith2::midpoint`](../trait.TupleArith2.html#method.midpoint) or
+//! [`TupleArith2::linspace`](../trait.TupleArith2.html#method.linspace).
+
+/// Types which have an overflow-safe midpoint.
+pub trait Midpoint {
+    /// Returns the midpoint of `self` and `other`.
+    fn midpoint(self, other: Self) -> Self;
+}
+
+macro_rules! impl_midpoint {
+    ($($t: ty)*) => {
+        $(
+            impl Midpoint for $t {
+                fn midpoint(self, other: Self) -> Self {
+                    <$t>::midpoint(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_midpoint!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+
+/// Types whose absolute difference can be computed without overflow.
+pub trait AbsDiff {
+    /// The type of the difference, e.g. `u8` for `i8`.
+    type Output;
+    /// Returns `|self - other|`.
+    fn abs_diff(self, other: Self) -> Self::Output;
+}
+
+macro_rules! impl_abs_diff_int {
+    ($($t: ty => $out: ty)*) => {
+        $(
+            impl AbsDiff for $t {
+                type Output = $out;
+                fn abs_diff(self, other: Self) -> $out {
+                    <$t>::abs_diff(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_abs_diff_int!(
+    i8 => u8 i16 => u16 i32 => u32 i64 => u64 i128 => u128 isize => usize
+    u8 => u8 u16 => u16 u32 => u32 u64 => u64 u128 => u128 usize => usize
+);
+
+macro_rules! impl_abs_diff_float {
+    ($($t: ty)*) => {
+        $(
+            impl AbsDiff for $t {
+                type Output = $t;
+                fn abs_diff(self, other: Self) -> $t {
+                    (self - other).abs()
+                }
+            }
+        )*
+    };
+}
+
+impl_abs_diff_float!(f32 f64);
+
+/// Floats ordered by IEEE 754 `totalOrder`, like `f64::total_cmp`.
+pub trait TotalOrd {
+    /// Compares `self` and `other` by total ordering.
+    fn total_cmp(&self, other: &Self) -> ::std::cmp::Ordering;
+}
+
+macro_rules! impl_total_ord {
+    ($($t: ty)*) => {
+        $(
+            impl TotalOrd for $t {
+                fn total_cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                    <$t>::total_cmp(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_total_ord!(f32 f64);
+
+/// Numeric types which can be converted into `f64`, possibly with loss of precision.
+pub trait ToF64 {
+    /// Converts `self` into `f64` by `as`.
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_to_f64 {
+    ($($t: ty)*) => {
+        $(
+            impl ToF64 for $t {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_to_f64!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+
+/// Floats which can be spaced evenly between two endpoints.
+pub trait Linspace {
+    /// Returns the `i`th of `len` values evenly spaced from `start` to `end`, both inclusive.
+    fn linspace_nth(start: Self, end: Self, i: usize, len: usize) -> Self;
+}
+
+macro_rules! impl_linspace {
+    ($($t: ty)*) => {
+        $(
+            impl Linspace for $t {
+                fn linspace_nth(start: Self, end: Self, i: usize, len: usize) -> Self {
+                    if len == 1 {
+                        start
+                    } else if i + 1 == len {
+                        end
+                    } else {
+                        start + (end - start) * (i as $t) / ((len - 1) as $t)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_linspace!(f32 f64);
@@ -0,0 +1,64 @@
+//! Mapping over tuples whose elements have different types.
+//!
+//! A closure can't be generic over its argument type, so
+//! [`PolyMap::poly_map`](trait.PolyMap.html#tymethod.poly_map) takes a value implementing
+//! [`Func`](trait.Func.html) for each element type instead.
+//! # Example
+//! ```
+//! # use tuple_map::poly::{Func, PolyMap};
+//! use std::fmt::Debug;
+//! struct DebugString;
+//! impl<T: Debug> Func<T> for DebugString {
+//!     type Output = String;
+//!     fn call(&mut self, x: T) -> String {
+//!         format!("{:?}", x)
+//!     }
+//! }
+//! let a = (1u8, "hi", 3.0f64).poly_map(DebugString);
+//! assert_eq!(a, ("1".to_owned(), "\"hi\"".to_owned(), "3.0".to_owned()));
+//! ```
+
+/// Functions which can be applied to values of type `T`.
+pub trait Func<T> {
+    /// The type of the result.
+    type Output;
+    /// Applies the function to `x`.
+    fn call(&mut self, x: T) -> Self::Output;
+}
+
+/// Tuples whose elements can be mapped by `F`, i.e. `F` implements [`Func`](trait.Func.html)
+/// for all element types.
+pub trait PolyMap<F> {
+    /// The tuple of results.
+    type Output;
+    /// Applies `f` to all elements from left to right and returns the tuple of results.
+    fn poly_map(self, f: F) -> Self::Output;
+}
+
+macro_rules! impl_poly_map {
+    ($(($ty: ident $name: ident))+) => {
+        impl_poly_map!(@outer [] [$(($ty $name))+]);
+    };
+    (@outer [$(($done_ty: ident $done: ident))*] [($ty: ident $name: ident) $($rest: tt)*]) => {
+        impl_poly_map!(@impl $(($done_ty $done))* ($ty $name));
+        impl_poly_map!(@outer [$(($done_ty $done))* ($ty $name)] [$($rest)*]);
+    };
+    (@outer [$($done: tt)*] []) => {};
+    (@impl $(($ty: ident $name: ident))+) => {
+        impl<Fun, $($ty),+> PolyMap<Fun> for ($($ty,)+)
+        where
+            $(Fun: Func<$ty>,)+
+        {
+            type Output = ($(<Fun as Func<$ty>>::Output,)+);
+            fn poly_map(self, mut f: Fun) -> Self::Output {
+                let ($($name,)+) = self;
+                ($(Func::<$ty>::call(&mut f, $name),)+)
+            }
+        }
+    };
+}
+
+impl_poly_map!(
+    (A a) (B b) (C c) (D d) (E e) (F f) (G g) (H h)
+    (I i) (J j) (K k) (L l) (M m) (N n) (O o) (P p)
+);
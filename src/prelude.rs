@@ -0,0 +1,28 @@
+//! Re-exports all tuple traits, so that `use tuple_map::prelude::*;` brings
+//! every method into scope.
+//! # Example
+//! ```
+//! extern crate tuple_map;
+//! use tuple_map::prelude::*;
+//! fn main() {
+//!     let a = (1, 2, 3);
+//!     assert_eq!(a.map(|x| x * 2), (2, 4, 6));
+//!     assert_eq!(a.add((1, 1, 1)), (2, 3, 4));
+//!     assert_eq!(a.into_vec(), vec![1, 2, 3]);
+//! }
+//! ```
+
+pub use {TupleMap1, TupleMap2, TupleMap3, TupleMap4, TupleMap5, TupleMap6, TupleMap7, TupleMap8,
+         TupleMap9, TupleMap10, TupleMap11, TupleMap12, TupleMap13, TupleMap14, TupleMap15,
+         TupleMap16};
+pub use {TupleArith1, TupleArith2, TupleArith3, TupleArith4, TupleArith5, TupleArith6,
+         TupleArith7, TupleArith8, TupleArith9, TupleArith10, TupleArith11, TupleArith12,
+         TupleArith13, TupleArith14, TupleArith15, TupleArith16};
+pub use {TupleConvert1, TupleConvert2, TupleConvert3, TupleConvert4, TupleConvert5,
+         TupleConvert6, TupleConvert7, TupleConvert8, TupleConvert9, TupleConvert10,
+         TupleConvert11, TupleConvert12, TupleConvert13, TupleConvert14, TupleConvert15,
+         TupleConvert16};
+pub use TupleMapEven;
+pub use arity::MapAt;
+pub use iter::CollectTuple;
+pub use poly::PolyMap;
@@ -0,0 +1,65 @@
+//! [proptest](https://docs.rs/proptest) strategies for tuples, enabled by `proptest` feature.
+//!
+//! Supported tuples are up to 12 elements, because proptest requires `Strategy::Value: Debug`.
+use proptest::array::{uniform, UniformArrayStrategy};
+use proptest::strategy::{Map, Strategy};
+use std::fmt::Debug;
+
+/// Tuples which can be generated by a strategy of their elements.
+pub trait TupleOf<S: Strategy>: Sized {
+    /// The strategy generating `Self`.
+    type Strategy: Strategy<Value = Self>;
+    /// Returns the strategy which generates every element by `element`.
+    fn tuple_of(element: S) -> Self::Strategy;
+}
+
+/// Returns the strategy generating `(a, a, a, ...)`, where each `a` is generated by `element`.
+/// Generated tuples are shrunk element-wise.
+/// # Example
+/// ```
+/// # #[macro_use] extern crate proptest;
+/// # extern crate tuple_map;
+/// use tuple_map::strategy::tuple_of;
+/// use tuple_map::*;
+/// proptest! {
+///     # #[allow(unused)]
+///     fn sum_is_small(a in tuple_of::<(_, _, _), _>(0..10)) {
+///         prop_assert!(a.sum() < 30);
+///     }
+/// }
+/// # fn main() { sum_is_small(); }
+/// ```
+pub fn tuple_of<U, S>(element: S) -> U::Strategy
+where
+    U: TupleOf<S>,
+    S: Strategy,
+{
+    U::tuple_of(element)
+}
+
+macro_rules! impl_tuple_of {
+    ($n: expr; $($name: ident)+; $($t: ident)+) => {
+        impl<T: Debug, S: Strategy<Value = T>> TupleOf<S> for ($($t,)+) {
+            type Strategy = Map<UniformArrayStrategy<S, [T; $n]>, fn([T; $n]) -> Self>;
+            fn tuple_of(element: S) -> Self::Strategy {
+                fn to_tuple<T>([$($name,)+]: [T; $n]) -> ($($t,)+) {
+                    ($($name,)+)
+                }
+                uniform(element).prop_map(to_tuple as fn([T; $n]) -> Self)
+            }
+        }
+    };
+}
+
+impl_tuple_of!(1; a; T);
+impl_tuple_of!(2; a b; T T);
+impl_tuple_of!(3; a b c; T T T);
+impl_tuple_of!(4; a b c d; T T T T);
+impl_tuple_of!(5; a b c d e; T T T T T);
+impl_tuple_of!(6; a b c d e f; T T T T T T);
+impl_tuple_of!(7; a b c d e f g; T T T T T T T);
+impl_tuple_of!(8; a b c d e f g h; T T T T T T T T);
+impl_tuple_of!(9; a b c d e f g h i; T T T T T T T T T);
+impl_tuple_of!(10; a b c d e f g h i j; T T T T T T T T T T);
+impl_tuple_of!(11; a b c d e f g h i j k; T T T T T T T T T T T);
+impl_tuple_of!(12; a b c d e f g h i j k l; T T T T T T T T T T T T);
@@ -0,0 +1,43 @@
+//! Helper trait for locks, used by
+//! [`TupleConvert2::lock_all`](../trait.TupleConvert2.html#method.lock_all) and
+//! [`TupleConvert2::try_lock_all`](../trait.TupleConvert2.html#method.try_lock_all).
+use std::sync::{Mutex, MutexGuard, RwLock, RwLockWriteGuard, TryLockError};
+
+/// Locks which can be acquired exclusively, i.e. `Mutex<T>` and `RwLock<T>`.
+pub trait Lock<'a> {
+    /// The RAII guard of the lock.
+    type Guard;
+    /// Acquires the lock, blocking the current thread until it is able to do so.
+    /// # Panics
+    /// Panics if the lock is poisoned.
+    fn lock(&'a self) -> Self::Guard;
+    /// Tries to acquire the lock, and returns `None` if it would block or it is poisoned.
+    fn try_lock(&'a self) -> Option<Self::Guard>;
+}
+
+impl<'a, T: ?Sized + 'a> Lock<'a> for Mutex<T> {
+    type Guard = MutexGuard<'a, T>;
+    fn lock(&'a self) -> Self::Guard {
+        Mutex::lock(self).expect("Mutex is poisoned")
+    }
+    fn try_lock(&'a self) -> Option<Self::Guard> {
+        match Mutex::try_lock(self) {
+            Ok(guard) => Some(guard),
+            Err(TryLockError::WouldBlock) | Err(TryLockError::Poisoned(_)) => None,
+        }
+    }
+}
+
+/// `RwLock` is locked for writing.
+impl<'a, T: ?Sized + 'a> Lock<'a> for RwLock<T> {
+    type Guard = RwLockWriteGuard<'a, T>;
+    fn lock(&'a self) -> Self::Guard {
+        RwLock::write(self).expect("RwLock is poisoned")
+    }
+    fn try_lock(&'a self) -> Option<Self::Guard> {
+        match RwLock::try_write(self) {
+            Ok(guard) => Some(guard),
+            Err(TryLockError::WouldBlock) | Err(TryLockError::Poisoned(_)) => None,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! Helper trait for elements which can be unwrapped, used by
+//! [`TupleConvert2::unwrap_all`](../trait.TupleConvert2.html#method.unwrap_all) and
+//! [`TupleConvert2::expect_all`](../trait.TupleConvert2.html#method.expect_all).
+use std::fmt::Debug;
+
+/// Types which can be unwrapped, i.e. `Option<T>` and `Result<T, E>`.
+pub trait Unwrap {
+    /// The type of the unwrapped value.
+    type Output;
+    /// Unwraps `self`, or panics with `index` and `msg`(if any) in the message.
+    fn unwrap_at(self, index: usize, msg: Option<&str>) -> Self::Output;
+}
+
+impl<T> Unwrap for Option<T> {
+    type Output = T;
+    fn unwrap_at(self, index: usize, msg: Option<&str>) -> T {
+        match self {
+            Some(x) => x,
+            None => match msg {
+                Some(msg) => panic!("{}: `None` value at index {}", msg, index),
+                None => panic!("called `unwrap_all()` on a `None` value at index {}", index),
+            },
+        }
+    }
+}
+
+impl<T, E: Debug> Unwrap for Result<T, E> {
+    type Output = T;
+    fn unwrap_at(self, index: usize, msg: Option<&str>) -> T {
+        match self {
+            Ok(x) => x,
+            Err(e) => match msg {
+                Some(msg) => panic!("{}: `Err` value at index {}: {:?}", msg, index, e),
+                None => panic!(
+                    "called `unwrap_all()` on an `Err` value at index {}: {:?}",
+                    index, e
+                ),
+            },
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Conversions between tuples and vectors of [glam](https://docs.rs/glam) or
+//! [nalgebra](https://docs.rs/nalgebra), enabled by `glam` or `nalgebra` feature.
+//!
+//! glam already implements `From<Vec2> for (f32, f32)` and so on, so tuples can be
+//! restored from glam vectors by `into()`.
+//! Examples are on `ToVec3` for glam and on `ToVector` for nalgebra, so that each of them
+//! runs only with its own feature.
+#[cfg(feature = "glam")]
+use glam::{DVec2, DVec3, DVec4, Vec2, Vec3, Vec4};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{SVector, Scalar};
+
+/// Tuples which can be converted into a 2D glam vector, i.e. `(f32, f32)` and `(f64, f64)`.
+#[cfg(feature = "glam")]
+pub trait ToVec2 {
+    /// The glam vector type.
+    type Output;
+    /// Converts `(x, y)` into a glam vector.
+    fn to_vec2(self) -> Self::Output;
+}
+
+/// Tuples which can be converted into a 3D glam vector, i.e. `(f32, f32, f32)` and
+/// `(f64, f64, f64)`.
+/// # Example
+/// ```
+/// # extern crate glam;
+/// # extern crate tuple_map;
+/// use tuple_map::vector::ToVec3;
+/// # fn main() {
+/// let v = (1.0f32, 2.0, 3.0).to_vec3();
+/// assert_eq!(v, glam::Vec3::new(1.0, 2.0, 3.0));
+/// let t: (f32, f32, f32) = (v * 2.0).into();
+/// assert_eq!(t, (2.0, 4.0, 6.0));
+/// # }
+/// ```
+#[cfg(feature = "glam")]
+pub trait ToVec3 {
+    /// The glam vector type.
+    type Output;
+    /// Converts `(x, y, z)` into a glam vector.
+    fn to_vec3(self) -> Self::Output;
+}
+
+/// Tuples which can be converted into a 4D glam vector, i.e. `(f32, f32, f32, f32)` and
+/// `(f64, f64, f64, f64)`.
+#[cfg(feature = "glam")]
+pub trait ToVec4 {
+    /// The glam vector type.
+    type Output;
+    /// Converts `(x, y, z, w)` into a glam vector.
+    fn to_vec4(self) -> Self::Output;
+}
+
+#[cfg(feature = "glam")]
+macro_rules! impl_to_glam {
+    ($trait: ident, $method: ident, $($t: ty => $out: ident ($($name: ident)+);)*) => {
+        $(
+            impl $trait for $t {
+                type Output = $out;
+                fn $method(self) -> $out {
+                    let ($($name,)+) = self;
+                    $out::new($($name),+)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "glam")]
+impl_to_glam!(ToVec2, to_vec2,
+              (f32, f32) => Vec2 (x y);
+              (f64, f64) => DVec2 (x y););
+#[cfg(feature = "glam")]
+impl_to_glam!(ToVec3, to_vec3,
+              (f32, f32, f32) => Vec3 (x y z);
+              (f64, f64, f64) => DVec3 (x y z););
+#[cfg(feature = "glam")]
+impl_to_glam!(ToVec4, to_vec4,
+              (f32, f32, f32, f32) => Vec4 (x y z w);
+              (f64, f64, f64, f64) => DVec4 (x y z w););
+
+/// Tuples which can be converted into a nalgebra column vector.
+/// # Example
+/// ```
+/// # extern crate tuple_map;
+/// use tuple_map::vector::{FromVector, ToVector};
+/// # fn main() {
+/// let v = (1.0, 2.0, 3.0, 4.0, 5.0).to_vector();
+/// assert_eq!(<(f64, f64, f64, f64, f64)>::from_vector(v * 2.0), (2.0, 4.0, 6.0, 8.0, 10.0));
+/// # }
+/// ```
+#[cfg(feature = "nalgebra")]
+pub trait ToVector {
+    /// The nalgebra vector type.
+    type Output;
+    /// Converts `(a, a, a, ...)` into a vector.
+    fn to_vector(self) -> Self::Output;
+}
+
+/// Tuples which can be restored from a nalgebra column vector.
+#[cfg(feature = "nalgebra")]
+pub trait FromVector: Sized {
+    /// The nalgebra vector type.
+    type Vector;
+    /// Converts a vector into `(a, a, a, ...)`.
+    fn from_vector(v: Self::Vector) -> Self;
+}
+
+#[cfg(feature = "nalgebra")]
+macro_rules! impl_vector {
+    ($n: expr; $($name: ident)+; $($t: ident)+) => {
+        impl<T: Scalar> ToVector for ($($t,)+) {
+            type Output = SVector<T, $n>;
+            fn to_vector(self) -> Self::Output {
+                let ($($name,)+) = self;
+                SVector::from([$($name,)+])
+            }
+        }
+
+        impl<T: Scalar> FromVector for ($($t,)+) {
+            type Vector = SVector<T, $n>;
+            fn from_vector(v: Self::Vector) -> Self {
+                let [$($name,)+]: [T; $n] = v.into();
+                ($($name,)+)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impls {
+    use super::*;
+    impl_vector!(1; a; T);
+    impl_vector!(2; a b; T T);
+    impl_vector!(3; a b c; T T T);
+    impl_vector!(4; a b c d; T T T T);
+    impl_vector!(5; a b c d e; T T T T T);
+    impl_vector!(6; a b c d e f; T T T T T T);
+    impl_vector!(7; a b c d e f g; T T T T T T T);
+    impl_vector!(8; a b c d e f g h; T T T T T T T T);
+    impl_vector!(9; a b c d e f g h i; T T T T T T T T T);
+    impl_vector!(10; a b c d e f g h i j; T T T T T T T T T T);
+    impl_vector!(11; a b c d e f g h i j k; T T T T T T T T T T T);
+    impl_vector!(12; a b c d e f g h i j k l; T T T T T T T T T T T T);
+    impl_vector!(13; a b c d e f g h i j k l m; T T T T T T T T T T T T T);
+    impl_vector!(14; a b c d e f g h i j k l m n; T T T T T T T T T T T T T T);
+    impl_vector!(15; a b c d e f g h i j k l m n o; T T T T T T T T T T T T T T T);
+    impl_vector!(16; a b c d e f g h i j k l m n o p; T T T T T T T T T T T T T T T T);
+}
@@ -11,13 +11,30 @@ max_items = 16
   items = (1..i).map { |_| 'Item'}.join(" ")
   p1 = (1..i).map { |_| param1}.join(" ")
   p2 = (1..i).map { |_| param2}.join(" ")
+  idx = (0...i).map { |j| j.to_s }.join(" ")
   impl += i.to_s + ",\n"
+  impl += indent + "TupleArith" + i.to_s + ",\n"
+  impl += indent + "TupleConvert" + i.to_s + ",\n"
   impl += indent + name_reduced + ", \n"
   impl += indent + names + ", \n"
   impl += indent + names2 + ", \n"
   impl += indent + items + ",\n"
   impl += indent + p1 + ", \n"
-  impl += indent + p2 + "\n}"
+  impl += indent + p2 + ", \n"
+  impl += indent + idx + "\n}"
   puts impl
 }
 
+
+init_even = "impl_tuple_map_even!{\n" + indent
+(2..max_items).step(2) { |i|
+  front = (0...i / 2).map { |j| ("a".ord + j).chr }.join(" ")
+  back = (i / 2...i).map { |j| ("a".ord + j).chr }.join(" ")
+  pairs = (0...i).step(2).map { |j| "(" + ("a".ord + j).chr + " " + ("a".ord + j + 1).chr + ")" }.join(" ")
+  p1 = (1..i).map { |_| param1}.join(" ")
+  impl = init_even + front + ",\n"
+  impl += indent + back + ",\n"
+  impl += indent + pairs + ",\n"
+  impl += indent + p1 + "\n}"
+  puts impl
+}